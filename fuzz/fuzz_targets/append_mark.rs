@@ -0,0 +1,95 @@
+//! Appending to a live chain with arbitrary commitments, signature, date,
+//! and info; a malformed append must be rejected, never panic
+
+#![no_main]
+
+use std::collections::BTreeMap;
+
+use dcbor::Date;
+use frost_ed25519::{Identifier, Signature, round1::SigningCommitments};
+use frost_pm_test::{
+    FrostGroup, FrostGroupConfig, FrostPmChain, message::MessagePurpose,
+};
+use libfuzzer_sys::fuzz_target;
+use provenance_mark::ProvenanceMarkResolution;
+use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+
+/// Decode 0xff-separated segments as commitments, alternating between the
+/// two sets and cycling through the group's identifiers
+fn commitment_sets(
+    bytes: &[u8],
+    ids: &[Identifier],
+) -> [BTreeMap<Identifier, SigningCommitments>; 2] {
+    let mut sets = [BTreeMap::new(), BTreeMap::new()];
+    for (i, segment) in bytes.split(|&b| b == 0xff).enumerate() {
+        if let Ok(commitments) = SigningCommitments::deserialize(segment) {
+            sets[i % 2].insert(ids[(i / 2) % ids.len()], commitments);
+        }
+    }
+    sets
+}
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 72 {
+        return;
+    }
+    let (signature, rest) = data.split_at(64);
+    let (seconds, rest) = rest.split_at(8);
+    let Ok(signature) = Signature::deserialize(signature) else {
+        return;
+    };
+    let seconds = i64::from_be_bytes(seconds.try_into().unwrap());
+
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Fuzzing appends".to_string(),
+    )
+    .unwrap();
+    let res = ProvenanceMarkResolution::Quartile;
+    let date_0 = Date::from_ymd(2025, 1, 1);
+    let message_0 = FrostPmChain::message_0(&config, res, date_0, None::<&str>);
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut rng).unwrap();
+    let signers = ["Alice", "Bob"];
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(&signers, &mut rng).unwrap();
+    let signature_0 = group
+        .round_2_sign_as(
+            MessagePurpose::Genesis,
+            &signers,
+            &commitments_0,
+            &nonces_0,
+            message_0.as_bytes(),
+        )
+        .unwrap();
+    let (commitments_1, _) = group.round_1_commit(&signers, &mut rng).unwrap();
+    let ids = group.config().participant_ids();
+    let (mut chain, ..) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        None::<&str>,
+        group,
+        signature_0,
+        &commitments_1,
+    )
+    .unwrap();
+
+    let [commitments, next_commitments] = commitment_sets(rest, &ids);
+    let date = Date::from_timestamp(seconds as f64);
+    let _ = chain.append_mark(
+        date,
+        Some(rest.to_vec()),
+        &commitments,
+        signature,
+        &next_commitments,
+    );
+    // The honest precommitted set with adversarial next commitments
+    let _ = chain.append_mark(
+        date,
+        None::<&str>,
+        &commitments_1,
+        signature,
+        &next_commitments,
+    );
+});
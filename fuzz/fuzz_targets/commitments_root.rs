@@ -0,0 +1,56 @@
+//! Commitment roots and precommit receipts over arbitrary commitment sets;
+//! both root encodings must agree and validation must never panic
+
+#![no_main]
+
+use std::collections::BTreeMap;
+
+use frost_ed25519::{Identifier, round1::SigningCommitments};
+use frost_pm_test::{
+    FrostGroup, FrostGroupConfig, FrostPmChain, PrecommitReceipt,
+};
+use libfuzzer_sys::fuzz_target;
+use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 36 {
+        return;
+    }
+    let (seq, rest) = data.split_at(4);
+    let (root, rest) = rest.split_at(32);
+    let seq = u32::from_be_bytes(seq.try_into().unwrap());
+    let root: [u8; 32] = root.try_into().unwrap();
+
+    // Each 0xff-separated segment is a two-byte identifier and commitments
+    let mut commitments = BTreeMap::new();
+    let mut ids = Vec::new();
+    for segment in rest.split(|&b| b == 0xff) {
+        let Some((id, bytes)) = segment.split_first_chunk::<2>() else {
+            continue;
+        };
+        let Ok(id) = Identifier::try_from(u16::from_be_bytes(*id)) else {
+            continue;
+        };
+        ids.push(id);
+        if let Ok(signing_commitments) = SigningCommitments::deserialize(bytes)
+        {
+            commitments.insert(id, signing_commitments);
+        }
+    }
+    assert_eq!(
+        FrostPmChain::commitments_root(&commitments),
+        FrostPmChain::commitments_root_streaming(&commitments)
+    );
+
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Fuzzing receipts".to_string(),
+    )
+    .unwrap();
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut rng).unwrap();
+    let _ = PrecommitReceipt::from_parts(seq, ids, commitments.clone(), root)
+        .validate(&group);
+    let _ = PrecommitReceipt::new(seq, commitments).validate(&group);
+});
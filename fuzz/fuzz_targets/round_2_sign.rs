@@ -0,0 +1,44 @@
+//! Co-located signing with an adversarial commitment, signature share, or
+//! message; every outcome must be an `Ok` or an error, never a panic
+
+#![no_main]
+
+use frost_ed25519::round1::SigningCommitments;
+use frost_pm_test::{FrostGroup, FrostGroupConfig, fuzzing};
+use libfuzzer_sys::fuzz_target;
+use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&mode, bytes)) = data.split_first() else {
+        return;
+    };
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Fuzzing round 2".to_string(),
+    )
+    .unwrap();
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut rng).unwrap();
+    let signers = ["Alice", "Bob"];
+    let (mut commitments, mut nonces) =
+        group.round_1_commit(&signers, &mut rng).unwrap();
+
+    // The first byte picks which input is adversarial; the message is always
+    // the remaining bytes
+    match mode % 4 {
+        0 => {
+            let Ok(bob) = SigningCommitments::deserialize(bytes) else {
+                return;
+            };
+            commitments.insert(group.name_to_id("Bob").unwrap(), bob);
+        }
+        1 => fuzzing::inject_share(Some(bytes.to_vec())),
+        2 => {
+            nonces.remove("Bob");
+        }
+        _ => {}
+    }
+    let _ = group.round_2_sign(&signers, &commitments, &nonces, bytes);
+    fuzzing::inject_share(None);
+});
@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Result, anyhow, bail};
+use frost_ed25519::{
+    Identifier, Signature, SigningKey, VerifyingKey,
+    rand_core::{CryptoRng, RngCore},
+    round1::SigningCommitments,
+};
+use provenance_mark::ProvenanceMark;
+
+use crate::{message::length_prefix, pm_chain::KdfVersion};
+
+/// Commitment map keyed by participant identifier
+type Commitments = BTreeMap<Identifier, SigningCommitments>;
+
+/// Archived evidence for a chain's genesis mark
+///
+/// Holds everything needed to rebuild the genesis message and re-derive
+/// key_0: the mark, the group's signature over the genesis message, and the
+/// optional predecessor key, identity, and KDF version the chain was created
+/// with.
+#[derive(Debug, Clone)]
+pub struct GenesisProof {
+    mark: ProvenanceMark,
+    signature: Signature,
+    predecessor_key: Option<VerifyingKey>,
+    predecessor_mark_hash: Option<Vec<u8>>,
+    identity: Option<Vec<u8>>,
+    kdf_version: KdfVersion,
+}
+
+impl GenesisProof {
+    /// Archive a genesis mark created by `FrostPmChain::new_chain`
+    pub fn new(mark: ProvenanceMark, signature: Signature) -> Self {
+        Self {
+            mark,
+            signature,
+            predecessor_key: None,
+            predecessor_mark_hash: None,
+            identity: None,
+            kdf_version: KdfVersion::V1,
+        }
+    }
+
+    /// Record the predecessor key passed to
+    /// `FrostPmChain::new_chain_with_predecessor`
+    pub fn with_predecessor_key(mut self, key: VerifyingKey) -> Self {
+        self.predecessor_key = Some(key);
+        self
+    }
+
+    /// Record the predecessor mark hash passed to
+    /// `FrostPmChain::new_chain_with_predecessor_chain`
+    pub fn with_predecessor_chain(mut self, mark_hash: &[u8]) -> Self {
+        self.predecessor_mark_hash = Some(mark_hash.to_vec());
+        self
+    }
+
+    /// Record the identity passed to `FrostPmChain::new_chain_with_identity`
+    pub fn with_identity(mut self, identity: &[u8]) -> Self {
+        self.identity = Some(identity.to_vec());
+        self
+    }
+
+    /// Record the KDF version passed to
+    /// `FrostPmChain::new_chain_with_kdf_version`
+    pub fn with_kdf_version(mut self, kdf_version: KdfVersion) -> Self {
+        self.kdf_version = kdf_version;
+        self
+    }
+
+    /// Get the genesis mark
+    pub fn mark(&self) -> &ProvenanceMark { &self.mark }
+
+    /// Get the group signature over the genesis message
+    pub fn signature(&self) -> &Signature { &self.signature }
+
+    /// Get the predecessor key bound into the genesis message, if any
+    pub fn predecessor_key(&self) -> Option<&VerifyingKey> {
+        self.predecessor_key.as_ref()
+    }
+
+    /// Get the prior chain's mark hash bound into the genesis message, if any
+    pub fn predecessor_chain(&self) -> Option<&[u8]> {
+        self.predecessor_mark_hash.as_deref()
+    }
+
+    /// Get the identity folded into key_0, if any
+    pub fn identity(&self) -> Option<&[u8]> { self.identity.as_deref() }
+
+    /// Get the KDF version the chain derives its keys with
+    pub fn kdf_version(&self) -> KdfVersion { self.kdf_version }
+}
+
+/// Archived evidence for one appended mark
+///
+/// The Round-1 commitments are the receipt from which the mark's key was
+/// derived, so an auditor can re-derive it without trusting the operator.
+#[derive(Debug, Clone)]
+pub struct MarkRecord {
+    mark: ProvenanceMark,
+    signature: Signature,
+    commitments: Commitments,
+    acks: Vec<CommitmentAck>,
+}
+
+impl MarkRecord {
+    /// Archive a mark with the signature and commitments used to append it
+    pub fn new(
+        mark: ProvenanceMark,
+        signature: Signature,
+        commitments: Commitments,
+    ) -> Self {
+        Self { mark, signature, commitments, acks: Vec::new() }
+    }
+
+    /// Attach participants' acknowledgments of their commitments
+    pub fn with_acks(mut self, acks: Vec<CommitmentAck>) -> Self {
+        self.acks = acks;
+        self
+    }
+
+    /// Get the appended mark
+    pub fn mark(&self) -> &ProvenanceMark { &self.mark }
+
+    /// Get the group signature over the mark's Round-2 message
+    pub fn signature(&self) -> &Signature { &self.signature }
+
+    /// Get the Round-1 commitments the mark's key was derived from
+    pub fn commitments(&self) -> &Commitments { &self.commitments }
+
+    /// Get the participants' acknowledgments of their commitments
+    pub fn acks(&self) -> &[CommitmentAck] { &self.acks }
+
+    /// Verify every ack against its participant's identity key
+    /// Each ack must cover the commitment that participant contributed to
+    /// this mark; participants without an ack are not an error
+    pub fn verify_acks(
+        &self,
+        identity_keys: &BTreeMap<Identifier, VerifyingKey>,
+    ) -> Result<()> {
+        for ack in &self.acks {
+            let key = identity_keys
+                .get(&ack.participant)
+                .ok_or_else(|| anyhow!("no identity key for ack signer"))?;
+            let commitment =
+                self.commitments.get(&ack.participant).ok_or_else(|| {
+                    anyhow!("ack from a participant that did not commit")
+                })?;
+            ack.verify(key, self.mark.chain_id(), self.mark.seq(), commitment)?;
+        }
+        Ok(())
+    }
+}
+
+/// A participant's signed acknowledgment of the Round-1 commitment it
+/// contributed to one mark
+///
+/// The aggregate FROST signature is deliberately the same whoever signed, so
+/// acks, made with each participant's own ed25519 identity key, are what
+/// later prove that a specific participant took part in a specific mark.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitmentAck {
+    participant: Identifier,
+    signature: Signature,
+}
+
+impl CommitmentAck {
+    /// Acknowledge `commitment`, contributed by `participant` for mark `seq`
+    /// of chain `chain_id`, with the participant's identity key
+    pub fn sign(
+        identity_key: &SigningKey,
+        participant: Identifier,
+        chain_id: &[u8],
+        seq: u32,
+        commitment: &SigningCommitments,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<Self> {
+        let message = ack_message(participant, chain_id, seq, commitment)?;
+        let signature = identity_key.sign(rng, &message);
+        Ok(Self { participant, signature })
+    }
+
+    /// Get the acknowledging participant's identifier
+    pub fn participant(&self) -> Identifier { self.participant }
+
+    /// Get the identity-key signature
+    pub fn signature(&self) -> &Signature { &self.signature }
+
+    /// Verify the ack under the participant's identity key
+    pub fn verify(
+        &self,
+        identity_key: &VerifyingKey,
+        chain_id: &[u8],
+        seq: u32,
+        commitment: &SigningCommitments,
+    ) -> Result<()> {
+        let message = ack_message(self.participant, chain_id, seq, commitment)?;
+        if identity_key.verify(&message, &self.signature).is_err() {
+            bail!("commitment ack for mark {} does not verify", seq);
+        }
+        Ok(())
+    }
+}
+
+/// The bytes a participant signs to acknowledge a commitment: a domain tag,
+/// the length-prefixed chain id, the seq, the identifier, and the commitment
+fn ack_message(
+    participant: Identifier,
+    chain_id: &[u8],
+    seq: u32,
+    commitment: &SigningCommitments,
+) -> Result<Vec<u8>> {
+    let mut message = b"PM:v1/commit-ack".to_vec();
+    message.extend_from_slice(&length_prefix(chain_id.len())?);
+    message.extend_from_slice(chain_id);
+    message.extend_from_slice(&seq.to_be_bytes());
+    message.extend_from_slice(&participant.serialize());
+    message.extend_from_slice(&commitment.serialize()?);
+    Ok(message)
+}
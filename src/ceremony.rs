@@ -0,0 +1,148 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use anyhow::{Result, anyhow};
+use chrono::DateTime;
+use dcbor::{CBOR, Date};
+use frost_ed25519::{Identifier, round1::SigningCommitments};
+
+/// Commitment map keyed by participant identifier
+type Commitments = BTreeMap<Identifier, SigningCommitments>;
+
+/// Serialized layout of a `CeremonyState`: seq, signers, commitments,
+/// next_commitments, message, date as exact (seconds, nanoseconds),
+/// CBOR-encoded info
+type CeremonyStateWire = (
+    u32,
+    Vec<String>,
+    Commitments,
+    Commitments,
+    String,
+    (i64, u32),
+    Option<Vec<u8>>,
+);
+
+/// In-flight state of an append ceremony between Round-1 and Round-2
+///
+/// Produced by `FrostPmChain::begin_append` and consumed by
+/// `FrostPmChain::complete_append`, so a coordinator can persist it across a
+/// restart while waiting for the Round-2 signature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CeremonyState {
+    seq: u32,
+    signers: Vec<String>,
+    commitments: Commitments,
+    next_commitments: Commitments,
+    message: String,
+    date: Date,
+    info: Option<CBOR>,
+}
+
+impl CeremonyState {
+    pub(crate) fn new(
+        seq: u32,
+        signers: Vec<String>,
+        commitments: Commitments,
+        next_commitments: Commitments,
+        message: String,
+        date: Date,
+        info: Option<CBOR>,
+    ) -> Self {
+        Self {
+            seq,
+            signers,
+            commitments,
+            next_commitments,
+            message,
+            date,
+            info,
+        }
+    }
+
+    /// Get the sequence number of the mark being appended
+    pub fn seq(&self) -> u32 { self.seq }
+
+    /// Get the names of the participants taking part in Round-2
+    pub fn signers(&self) -> &[String] { &self.signers }
+
+    /// Get the Round-1 commitments for this mark
+    pub fn commitments(&self) -> &Commitments { &self.commitments }
+
+    /// Get the Round-1 commitments precommitted for the following mark
+    pub fn next_commitments(&self) -> &Commitments { &self.next_commitments }
+
+    /// Get the message the signers must sign in Round-2
+    pub fn message(&self) -> &str { &self.message }
+
+    /// Get the date of the mark being appended
+    pub fn date(&self) -> Date { self.date }
+
+    /// Get the info of the mark being appended
+    pub fn info(&self) -> Option<&CBOR> { self.info.as_ref() }
+
+    /// Serialize the state for persistence
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let wire: CeremonyStateWire = (
+            self.seq,
+            self.signers.clone(),
+            self.commitments.clone(),
+            self.next_commitments.clone(),
+            self.message.clone(),
+            (
+                self.date.datetime().timestamp(),
+                self.date.datetime().timestamp_subsec_nanos(),
+            ),
+            self.info.as_ref().map(|info| info.to_cbor_data()),
+        );
+        Ok(bincode::serde::encode_to_vec(
+            &wire,
+            bincode::config::standard(),
+        )?)
+    }
+
+    /// Restore a state previously produced by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (wire, _): (CeremonyStateWire, usize) =
+            bincode::serde::decode_from_slice(
+                bytes,
+                bincode::config::standard(),
+            )?;
+        let (seq, signers, commitments, next_commitments, message, date, info) =
+            wire;
+        let date = DateTime::from_timestamp(date.0, date.1)
+            .map(Date::from_datetime)
+            .ok_or_else(|| anyhow!("invalid ceremony state date"))?;
+        let info = info.map(CBOR::try_from_data).transpose()?;
+        Ok(Self::new(
+            seq,
+            signers,
+            commitments,
+            next_commitments,
+            message,
+            date,
+            info,
+        ))
+    }
+}
+
+/// Shared flag for cancelling an in-flight signing ceremony
+/// Clones share the flag, so a service can hand one to a ceremony and keep
+/// another to cancel it, e.g. on shutdown
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that is not yet cancelled
+    pub fn new() -> Self { Self::default() }
+
+    /// Cancel every ceremony watching this token
+    pub fn cancel(&self) { self.0.store(true, Ordering::SeqCst); }
+
+    /// Whether `cancel` has been called on this token or a clone
+    pub fn is_cancelled(&self) -> bool { self.0.load(Ordering::SeqCst) }
+}
@@ -1,12 +1,183 @@
+use std::time::{Duration, Instant};
+
 use anyhow::{Result, bail};
 use dcbor::Date;
-use frost_pm_test::{
-    FrostGroup, FrostGroupConfig, pm_chain::FrostPmChain, rand_core::OsRng,
+use provenance_mark::{ProvenanceMark, ProvenanceMarkResolution};
+
+use crate::{
+    FrostGroup, FrostGroupConfig,
+    message::MessagePurpose,
+    pm_chain::{AppendTimings, FrostPmChain},
+    rand_core::OsRng,
 };
-use provenance_mark::ProvenanceMarkResolution;
 
 const MARK_COUNT: usize = 100;
 
+/// Signers used for every mark in the demo chains
+const SIGNERS: &[&str] = &["Alice", "Bob"];
+
+/// Outcome of building and validating one demo chain
+#[derive(Debug, Clone)]
+pub struct ChainReport {
+    /// Resolution of the chain
+    pub res: ProvenanceMarkResolution,
+    /// All marks in the chain, genesis first
+    pub marks: Vec<ProvenanceMark>,
+    /// Whether the first mark is a valid genesis mark
+    pub genesis_valid: bool,
+    /// Whether the provenance-mark crate accepts the whole sequence
+    pub sequence_valid: bool,
+    /// Number of precedence spot checks that passed
+    pub precedence_checks_passed: usize,
+    /// Number of precedence spot checks performed
+    pub precedence_checks_total: usize,
+    /// Whether every mark has the chain's resolution
+    pub resolution_consistent: bool,
+    /// Accumulated `append_mark` phase timings across all appends
+    pub append_timings: AppendTimings,
+    /// Time spent validating the finished chain
+    pub validation_time: Duration,
+}
+
+impl ChainReport {
+    /// Whether all precedence spot checks passed
+    pub fn precedence_valid(&self) -> bool {
+        self.precedence_checks_passed == self.precedence_checks_total
+    }
+
+    /// Whether every validation check passed
+    pub fn is_valid(&self) -> bool {
+        self.genesis_valid
+            && self.sequence_valid
+            && self.precedence_valid()
+            && self.resolution_consistent
+    }
+}
+
+/// Build a `count`-mark chain signed by Alice and Bob and validate it
+/// This is the core of the demo, usable as a test fixture or benchmark
+pub fn build_and_validate_chain(
+    group: &FrostGroup,
+    res: ProvenanceMarkResolution,
+    count: usize,
+) -> Result<ChainReport> {
+    if count < 2 {
+        bail!("a demo chain needs at least 2 marks, got {}", count);
+    }
+
+    // Client generates genesis message and signs it
+    let date_0 = Date::now();
+    let info_0 =
+        Some(format!("Digital artwork collection ({} resolution)", res));
+    let message_0 =
+        FrostPmChain::message_0(group.config(), res, date_0, info_0.clone());
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(SIGNERS, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        SIGNERS,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+
+    // Client generates Round-1 commitments for seq=1
+    let (commitments_1, nonces_1) =
+        group.round_1_commit(SIGNERS, &mut OsRng)?;
+
+    // Genesis
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0,
+        group.clone(),
+        signature_0,
+        &commitments_1,
+    )?;
+
+    // The client keeps the seq1_nonces for the first append_mark
+    let mut current_nonces = nonces_1;
+    let mut current_commitments = commitments_1;
+
+    // Store all marks for final validation
+    let mut marks = vec![mark_0];
+
+    // Accumulated append_mark phase timings for the throughput report
+    let mut append_timings = AppendTimings::default();
+
+    for seq in 1..count {
+        // Vary the content for each mark
+        let info = Some(format!("Edition #{}", seq));
+        let date = Date::now();
+
+        // Client generates message and Round-2 signature
+        let message = chain.message_next(date, info.clone());
+        let signature = chain.group().round_2_sign_as(
+            MessagePurpose::Mark,
+            SIGNERS,
+            &current_commitments,
+            &current_nonces,
+            message.as_bytes(),
+        )?;
+
+        // Generate commitments for next sequence
+        let (next_commitments, new_nonces) =
+            chain.group().round_1_commit(SIGNERS, &mut OsRng)?;
+
+        let (mark, timings) = chain.append_mark_timed(
+            date,
+            info,
+            &current_commitments,
+            signature,
+            &next_commitments,
+        )?;
+        append_timings.root += timings.root;
+        append_timings.kdf += timings.kdf;
+        append_timings.verify += timings.verify;
+        append_timings.precommit += timings.precommit;
+        append_timings.finalize += timings.finalize;
+        append_timings.total += timings.total;
+
+        // Update for next iteration
+        current_nonces = new_nonces;
+        current_commitments = next_commitments;
+
+        marks.push(mark);
+    }
+
+    // Comprehensive chain validation
+    let start_time = Instant::now();
+
+    let genesis_valid = marks[0].is_genesis();
+    let sequence_valid = ProvenanceMark::is_sequence_valid(&marks);
+
+    // Spot check precedence for performance (checking every link would be
+    // slow for long chains)
+    let step = ((count - 1) / 7).max(1);
+    let check_indices: Vec<usize> = (0..count - 1).step_by(step).collect();
+    let precedence_checks_passed = check_indices
+        .iter()
+        .filter(|&&i| marks[i].precedes(&marks[i + 1]))
+        .count();
+
+    // Check resolution consistency
+    let resolution_consistent = marks.iter().all(|m| m.res() == res);
+
+    let validation_time = start_time.elapsed();
+
+    Ok(ChainReport {
+        res,
+        marks,
+        genesis_valid,
+        sequence_valid,
+        precedence_checks_passed,
+        precedence_checks_total: check_indices.len(),
+        resolution_consistent,
+        append_timings,
+        validation_time,
+    })
+}
+
 pub fn run_demo() -> Result<()> {
     println!("🔒 FROST-Controlled Provenance Mark Chain Demo");
     println!("===============================================");
@@ -33,7 +204,7 @@ pub fn run_demo() -> Result<()> {
         (ProvenanceMarkResolution::High, "🔴"),
     ];
 
-    for (i, (res, icon)) in resolutions.iter().enumerate() {
+    for (res, icon) in resolutions {
         println!(
             "{} ═══ {} Resolution Demo - {} Mark Chain ({} bytes) ═══",
             icon,
@@ -42,190 +213,92 @@ pub fn run_demo() -> Result<()> {
             res.link_length()
         );
 
-        // Genesis data
-        let artwork_name =
-            format!("Digital artwork collection #{} - 2024", i + 1);
+        print!("   Creating and validating {} marks... ", MARK_COUNT);
+        let report = build_and_validate_chain(&group, res, MARK_COUNT)?;
+        println!("✓ Complete!");
+        print_report(&report, icon)?;
+    }
 
-        println!("   Collection: \"{}\"", artwork_name);
+    println!("🎉 {}-Mark Chain Demo Complete!", MARK_COUNT);
 
-        // Client generates genesis message and signs it
-        let date_0 = Date::now();
-        let info_0 = Some(artwork_name);
-        let message_0 = FrostPmChain::message_0(
-            group.config(),
-            *res,
-            date_0,
-            info_0.clone(),
-        );
-        let (commitments_0, nonces_0) =
-            group.round_1_commit(&["Alice", "Bob"], &mut OsRng)?;
-        let signature_0 = group.round_2_sign(
-            &["Alice", "Bob"],
-            &commitments_0,
-            &nonces_0,
-            message_0.as_bytes(),
-        )?;
+    Ok(())
+}
 
-        // Client generates Round-1 commitments for seq=1
-        let (commitments_1, nonces_1) =
-            group.round_1_commit(&["Alice", "Bob"], &mut OsRng)?;
-
-        // Genesis
-        let (mut chain, mark_0) = FrostPmChain::new_chain(
-            *res,
-            date_0,
-            info_0,
-            group.clone(),
-            signature_0,
-            &commitments_1,
-        )?;
+/// Format a chain report for the console
+fn print_report(report: &ChainReport, icon: &str) -> Result<()> {
+    let marks = &report.marks;
+    let mark_0 = &marks[0];
+    println!(
+        "   ✓ Genesis mark: {} (link: {} bytes)",
+        mark_0.id_hex(),
+        mark_0.key().len()
+    );
+    println!("   Chain ID: {}", hex::encode(mark_0.chain_id()));
 
-        // The client keeps the seq1_nonces for the first append_mark
-        let mut current_nonces = nonces_1;
-        let mut current_commitments = commitments_1;
+    // Throughput report from append_mark phase timings
+    let timings = &report.append_timings;
+    let appended = (marks.len() - 1) as u32;
+    let per_mark_us =
+        |d: Duration| (d / appended).as_secs_f64() * 1_000_000.0;
+    println!(
+        "   ⏱  append_mark: {:.0} marks/s ({:.1}µs/mark)",
+        appended as f64 / timings.total.as_secs_f64(),
+        per_mark_us(timings.total)
+    );
+    println!(
+        "      root {:.1}µs, kdf {:.1}µs, verify {:.1}µs, precommit {:.1}µs, finalize {:.1}µs",
+        per_mark_us(timings.root),
+        per_mark_us(timings.kdf),
+        per_mark_us(timings.verify),
+        per_mark_us(timings.precommit),
+        per_mark_us(timings.finalize)
+    );
+    println!(
+        "      append_mark_trusted would save {:.1}µs/mark ({:.0}%)",
+        per_mark_us(timings.verify),
+        timings.verify.as_secs_f64() / timings.total.as_secs_f64() * 100.0
+    );
 
-        println!(
-            "   ✓ Genesis mark: {} (link: {} bytes)",
-            mark_0.identifier(),
-            mark_0.key().len()
-        );
-        println!("   Chain ID: {}", hex::encode(mark_0.chain_id()));
-
-        // Store all marks for final validation
-        let mut all_marks = vec![mark_0];
-
-        print!("   Creating marks: ");
-        for seq in 1..MARK_COUNT {
-            // Vary the content for each mark
-            let info =
-                Some(format!("Edition #{} of collection #{}", seq, i + 1));
-            let date = Date::now();
-
-            // Client generates message and Round-2 signature
-            let message = chain.message_next(date, info.clone());
-
-            let signers = &["Alice", "Bob"];
-
-            let signature = chain.group().round_2_sign(
-                signers,
-                &current_commitments,
-                &current_nonces,
-                message.as_bytes(),
-            )?;
-
-            // Generate commitments for next sequence
-            let (next_commitments, new_nonces) =
-                chain.group().round_1_commit(signers, &mut OsRng)?;
-
-            let mark = chain.append_mark(
-                date,
-                info,
-                &current_commitments,
-                signature,
-                &next_commitments,
-            )?;
-
-            // Update for next iteration
-            current_nonces = new_nonces;
-            current_commitments = next_commitments;
-
-            all_marks.push(mark);
-
-            // Progress indicator - print every 10 marks
-            if seq % 10 == 0 {
-                print!("{}.", seq);
-            } else if seq % 5 == 0 {
-                print!("•");
-            }
-        }
-        println!(" ✓ Complete!");
-
-        // Show sample marks from the chain
-        let last_mark_index = MARK_COUNT - 1;
-        let mid_mark_index = MARK_COUNT / 2 - 1;
-        let last_mark = &all_marks[last_mark_index];
-        let mid_mark = &all_marks[mid_mark_index];
-        println!("   Sample marks:");
-        println!(
-            "     Mark #1:  {} (seq={})",
-            all_marks[1].identifier(),
-            all_marks[1].seq()
-        );
-        println!(
-            "     Mark #{}: {} (seq={})",
-            mid_mark_index + 1,
-            mid_mark.identifier(),
-            mid_mark.seq()
-        );
+    // Show sample marks from the chain
+    let last_mark_index = marks.len() - 1;
+    let mid_mark_index = marks.len() / 2 - 1;
+    println!("   Sample marks:");
+    for index in [1, mid_mark_index, last_mark_index] {
         println!(
             "     Mark #{}: {} (seq={})",
-            last_mark_index + 1,
-            last_mark.identifier(),
-            last_mark.seq()
-        );
-
-        // Comprehensive chain validation
-        print!("   Validating {}-mark chain... ", MARK_COUNT);
-        let start_time = std::time::Instant::now();
-
-        let genesis_check = all_marks[0].is_genesis();
-        let sequence_valid =
-            provenance_mark::ProvenanceMark::is_sequence_valid(&all_marks);
-
-        // Spot check precedence for performance (checking all 99 links would be
-        // slow)
-        let mut spot_checks_passed = 0;
-        let check_indices: Vec<usize> =
-            (0..MARK_COUNT - 1).step_by((MARK_COUNT - 1) / 7).collect();
-        for &i in &check_indices {
-            if all_marks[i].precedes(&all_marks[i + 1]) {
-                spot_checks_passed += 1;
-            }
-        }
-        let precedence_valid = spot_checks_passed == check_indices.len();
-
-        // Check resolution consistency
-        let resolution_consistent = all_marks.iter().all(|m| m.res() == *res);
-
-        let validation_time = start_time.elapsed();
-        println!("Done ({:.2}ms)", validation_time.as_secs_f64() * 1000.0);
-
-        println!("   📋 Chain Verification:");
-        println!(
-            "     Genesis check: {}",
-            if genesis_check { "✅" } else { "❌" }
+            index + 1,
+            marks[index].id_hex(),
+            marks[index].seq()
         );
-        println!(
-            "     Sequence validity: {}",
-            if sequence_valid { "✅" } else { "❌" }
-        );
-        println!(
-            "     Precedence spot checks ({}/{}): {}",
-            spot_checks_passed,
-            check_indices.len(),
-            if precedence_valid { "✅" } else { "❌" }
-        );
-        println!(
-            "     Resolution consistency: {}",
-            if resolution_consistent { "✅" } else { "❌" }
-        );
-        println!("     Chain length: {} marks", all_marks.len());
-
-        if genesis_check
-            && sequence_valid
-            && precedence_valid
-            && resolution_consistent
-        {
-            println!(
-                "   {} {} resolution {}-mark chain verified successfully!\n",
-                icon, res, MARK_COUNT
-            );
-        } else {
-            bail!("Chain verification failed for {} resolution", res);
-        }
     }
 
-    println!("🎉 {}-Mark Chain Demo Complete!", MARK_COUNT);
+    let check = |ok: bool| if ok { "✅" } else { "❌" };
+    println!(
+        "   📋 Chain Verification ({:.2}ms):",
+        report.validation_time.as_secs_f64() * 1000.0
+    );
+    println!("     Genesis check: {}", check(report.genesis_valid));
+    println!("     Sequence validity: {}", check(report.sequence_valid));
+    println!(
+        "     Precedence spot checks ({}/{}): {}",
+        report.precedence_checks_passed,
+        report.precedence_checks_total,
+        check(report.precedence_valid())
+    );
+    println!(
+        "     Resolution consistency: {}",
+        check(report.resolution_consistent)
+    );
+    println!("     Chain length: {} marks", marks.len());
 
+    if !report.is_valid() {
+        bail!("Chain verification failed for {} resolution", report.res);
+    }
+    println!(
+        "   {} {} resolution {}-mark chain verified successfully!\n",
+        icon,
+        report.res,
+        marks.len()
+    );
     Ok(())
 }
@@ -0,0 +1,158 @@
+use std::{fmt, time::Duration};
+
+use frost_ed25519::Identifier;
+use provenance_mark::ProvenanceMarkResolution;
+
+/// Typed errors for FROST chain conditions callers may want to match on
+///
+/// These are raised through `anyhow::Result` like every other error in the
+/// crate; use `anyhow::Error::downcast_ref::<FrostError>()` to inspect them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrostError {
+    /// A commitment set has fewer entries than the group threshold
+    InsufficientCommitments { required: usize, got: usize },
+    /// A commitment set contains an identifier that is not a group member
+    UnknownCommitmentParticipant,
+    /// Aggregation failed; lists the names of every participant whose
+    /// signature share did not verify
+    InvalidSignatureShares { culprits: Vec<String> },
+    /// The group's charter no longer matches the one bound at genesis
+    CharterChangedAfterGenesis,
+    /// A signing ceremony could not reach the threshold
+    /// `committed` lists participants that produced Round-1 commitments and
+    /// `signed` those that were still able to produce a Round-2 share
+    CeremonyAborted {
+        committed: Vec<Identifier>,
+        signed: Vec<Identifier>,
+        reason: String,
+    },
+    /// A charter exceeds the config's maximum length in bytes
+    CharterTooLong { max: usize, got: usize },
+    /// Serialized key material was written by an unsupported frost library
+    /// version
+    UnsupportedKeyVersion { found: String, expected: String },
+    /// A signing ceremony did not finish within the caller's time limit
+    Timeout { limit: Duration },
+    /// The RNG handed to a trusted dealer looks degenerate (debug builds)
+    WeakRng,
+    /// A signing ceremony was cancelled through its `CancellationToken`
+    /// `committed` lists participants that had produced Round-1 commitments
+    Cancelled { committed: Vec<Identifier> },
+    /// Signature bytes could not be parsed as a group signature
+    MalformedSignature { len: usize },
+    /// An aggregated signature did not survive a serialization round trip
+    /// unchanged, so it could not be safely archived and reparsed
+    NonCanonicalSignature,
+    /// A participant's key package does not belong to the supplied public
+    /// key package
+    KeyMaterialMismatch { participant: String },
+    /// The public key package's verifying shares do not interpolate to its
+    /// group verifying key
+    VerifyingSharesMismatch,
+    /// An append's next commitments are the ones it is consuming, whose
+    /// nonces are spent once it signs
+    StaleNextCommitments,
+    /// A chain was requested at a resolution below the group's minimum
+    ResolutionBelowPolicy {
+        minimum: ProvenanceMarkResolution,
+        got: ProvenanceMarkResolution,
+    },
+    /// An append's commitments are the ones the tip was just appended with:
+    /// a concurrent proposer won the race to fix the previous mark's next
+    /// key, as opposed to the caller supplying unrelated commitments
+    NextKeyAlreadyCommitted,
+    /// A group decoded from public material only was asked to sign
+    PublicOnlyGroup,
+    /// A signing package's commitments do not produce the group commitment
+    /// `R` of the signature it is offered with
+    UnboundCommitments,
+}
+
+impl fmt::Display for FrostError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrostError::InsufficientCommitments { required, got } => write!(
+                f,
+                "insufficient commitments: need at least {}, got {}",
+                required, got
+            ),
+            FrostError::UnknownCommitmentParticipant => {
+                write!(f, "commitment from unknown participant")
+            }
+            FrostError::InvalidSignatureShares { culprits } => write!(
+                f,
+                "invalid signature shares from: {}",
+                culprits.join(", ")
+            ),
+            FrostError::CharterChangedAfterGenesis => {
+                write!(f, "group charter changed after genesis")
+            }
+            FrostError::CeremonyAborted { committed, signed, reason } => {
+                write!(
+                    f,
+                    "ceremony aborted ({} committed, {} signed): {}",
+                    committed.len(),
+                    signed.len(),
+                    reason
+                )
+            }
+            FrostError::CharterTooLong { max, got } => write!(
+                f,
+                "charter too long: at most {} bytes allowed, got {}",
+                max, got
+            ),
+            FrostError::UnsupportedKeyVersion { found, expected } => write!(
+                f,
+                "unsupported key format \"{}\", expected \"{}\"",
+                found, expected
+            ),
+            FrostError::Timeout { limit } => {
+                write!(f, "signing ceremony timed out after {:?}", limit)
+            }
+            FrostError::WeakRng => {
+                write!(f, "RNG output looks degenerate; refusing to deal keys")
+            }
+            FrostError::Cancelled { committed } => write!(
+                f,
+                "signing ceremony cancelled ({} committed)",
+                committed.len()
+            ),
+            FrostError::MalformedSignature { len } => {
+                write!(f, "malformed signature ({} bytes)", len)
+            }
+            FrostError::NonCanonicalSignature => {
+                write!(f, "aggregated signature has no canonical encoding")
+            }
+            FrostError::KeyMaterialMismatch { participant } => write!(
+                f,
+                "key package for {} does not match the public key package",
+                participant
+            ),
+            FrostError::VerifyingSharesMismatch => write!(
+                f,
+                "verifying shares do not interpolate to the group verifying key"
+            ),
+            FrostError::StaleNextCommitments => {
+                write!(f, "next commitments reuse the current commitments")
+            }
+            FrostError::ResolutionBelowPolicy { minimum, got } => write!(
+                f,
+                "resolution {} is below the group minimum of {}",
+                got, minimum
+            ),
+            FrostError::NextKeyAlreadyCommitted => write!(
+                f,
+                "commitments were already consumed by a concurrent append"
+            ),
+            FrostError::PublicOnlyGroup => {
+                write!(f, "group holds only public material and cannot sign")
+            }
+            FrostError::UnboundCommitments => write!(
+                f,
+                "signing package commitments do not match the signature"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FrostError {}
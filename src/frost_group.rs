@@ -1,28 +1,77 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+    sync::{Arc, mpsc},
+    thread,
+    time::{Duration, Instant},
+};
 
 use anyhow::{Result, anyhow, bail};
+use dcbor::{CBOR, Map};
 use frost_ed25519 as frost;
+use frost_core::Group;
 use frost_ed25519::{
-    Identifier, Signature, SigningPackage,
-    keys::{KeyPackage, PublicKeyPackage},
+    Ed25519Group, Identifier, Signature, SigningPackage,
+    keys::{
+        KeyPackage, PublicKeyPackage, SigningShare, VerifyingShare, dkg,
+    },
     rand_core::{CryptoRng, RngCore},
     round1::{SigningCommitments, SigningNonces},
     round2::SignatureShare,
 };
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{
+    CancellationToken, FrostError, GroupWarning, PublicGroupSummary,
+    frost_group_config::{FrostGroupConfig, FrostGroupConfigBuilder},
+    message::{MessagePurpose, aad_message, framed_message},
+    observer::Observer,
+    public_summary::{GroupAttestation, key_fingerprint},
+    share_envelope::{FROST_KEY_FORMAT, ShareEnvelope},
+};
+
+/// Message length above which `round_2_sign` warns that prehashing is
+/// recommended
+pub const PREHASH_RECOMMENDED_LEN: usize = 1024 * 1024;
+
+/// Smoke-test a dealer's RNG in debug builds, failing with `WeakRng` if two
+/// successive draws are equal or zero
+/// `CryptoRng` is only a marker, so this catches broken wiring such as a
+/// constant test source, not subtle bias. The two draws are consumed in
+/// every build, so a seeded RNG deals the same keys in debug and release
+fn check_rng(rng: &mut impl RngCore) -> Result<()> {
+    let (a, b) = (rng.next_u64(), rng.next_u64());
+    if cfg!(debug_assertions) && (a == b || a == 0 || b == 0) {
+        bail!(FrostError::WeakRng);
+    }
+    Ok(())
+}
 
-use crate::frost_group_config::FrostGroupConfig;
+/// How often `sign_cancellable` checks its cancellation token
+const CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Progress reported by a signing worker thread
+enum WorkerEvent {
+    /// Round 1 finished with commitments from these participants
+    Committed(Vec<Identifier>),
+    /// Both rounds finished, successfully or not
+    Finished(Result<Signature>),
+}
 
 /// A fully constituted FROST group with all key material needed for signing
 /// This type abstracts away whether keys were generated via trusted dealer or
-/// DKG
+/// DKG. Clones share one copy of the key material, so a group can be cloned
+/// into each thread that signs with it.
 #[derive(Debug, Clone)]
 pub struct FrostGroup {
     /// Configuration for the FROST group parameters
     config: FrostGroupConfig,
     /// Key packages for each participant (contains signing shares)
-    key_packages: BTreeMap<Identifier, KeyPackage>,
+    key_packages: Arc<KeyMaterial>,
     /// The group's public key package (for verification and coordination)
     public_key_package: PublicKeyPackage,
+    /// Receives advisory warnings, if set
+    observer: Option<Observer>,
 }
 
 impl FrostGroup {
@@ -31,6 +80,17 @@ impl FrostGroup {
         config: FrostGroupConfig,
         rng: &mut (impl RngCore + CryptoRng),
     ) -> Result<Self> {
+        check_rng(rng)?;
+        if config.is_single_signer() {
+            let (key_packages, public_key_package) =
+                Self::deal_single_signer(&config, rng)?;
+            return Self::new_from_key_material(
+                config,
+                key_packages,
+                public_key_package,
+            );
+        }
+
         // Generate secret shares using trusted dealer
         let (secret_shares, public_key_package) =
             frost::keys::generate_with_dealer(
@@ -52,12 +112,44 @@ impl FrostGroup {
         Self::new_from_key_material(config, key_packages, public_key_package)
     }
 
+    /// Deal key material for a 1-of-N group, which frost's dealer rejects
+    /// With threshold 1 the sharing polynomial is constant, so every
+    /// participant's share is the group secret itself
+    fn deal_single_signer(
+        config: &FrostGroupConfig,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<(BTreeMap<Identifier, KeyPackage>, PublicKeyPackage)> {
+        let signing_key = frost::SigningKey::new(rng);
+        let secret = Zeroizing::new(signing_key.serialize());
+        let signing_share = SigningShare::deserialize(&secret)?;
+        let verifying_share = VerifyingShare::from(signing_share);
+        let verifying_key = frost::VerifyingKey::from(&signing_key);
+        let mut key_packages = BTreeMap::new();
+        let mut verifying_shares = BTreeMap::new();
+        for id in config.participant_ids() {
+            let key_package = KeyPackage::new(
+                id,
+                signing_share,
+                verifying_share,
+                verifying_key,
+                1,
+            );
+            key_packages.insert(id, key_package);
+            verifying_shares.insert(id, verifying_share);
+        }
+        let public_key_package =
+            PublicKeyPackage::new(verifying_shares, verifying_key);
+        Ok((key_packages, public_key_package))
+    }
+
     /// Create a new FROSTGroup from existing key material (e.g., from DKG)
     pub fn new_from_key_material(
         config: FrostGroupConfig,
         key_packages: BTreeMap<Identifier, KeyPackage>,
         public_key_package: PublicKeyPackage,
     ) -> Result<Self> {
+        Self::check_threshold(&config, key_packages.values())?;
+
         // Validate that we have key packages for all participants
         if key_packages.len() != config.max_signers() {
             bail!(
@@ -78,7 +170,148 @@ impl FrostGroup {
             }
         }
 
-        Ok(Self { config, key_packages, public_key_package })
+        Self::check_key_material(&config, &key_packages, &public_key_package)?;
+
+        Ok(Self {
+            config,
+            key_packages: Arc::new(KeyMaterial(key_packages)),
+            public_key_package,
+            observer: None,
+        })
+    }
+
+    /// Create a single-participant FROSTGroup from an exported share
+    /// The resulting group holds only the enveloped participant's key package
+    /// and can take part in distributed signing for that participant
+    pub fn import_share(
+        config: FrostGroupConfig,
+        public_key_package: PublicKeyPackage,
+        envelope: &ShareEnvelope,
+    ) -> Result<Self> {
+        let (name, key_package) = envelope.open()?;
+        if !config.participants().contains_key(&name) {
+            bail!("Unknown participant: {}", name);
+        }
+        Self::new_for_participant(config, public_key_package, key_package)
+    }
+
+    /// Create a single-participant FROSTGroup from one key package
+    /// Validates that the key package belongs to a configured participant of
+    /// the group described by `public_key_package`
+    pub fn new_for_participant(
+        config: FrostGroupConfig,
+        public_key_package: PublicKeyPackage,
+        key_package: KeyPackage,
+    ) -> Result<Self> {
+        let id = *key_package.identifier();
+        let name = config.participant_name(&id).to_string();
+        if !config.participant_ids().contains(&id) {
+            bail!("Key package identifier is not a configured participant");
+        }
+        if key_package.verifying_key() != public_key_package.verifying_key() {
+            bail!("Share for {} belongs to a different group", name);
+        }
+        if public_key_package.verifying_shares().get(&id)
+            != Some(key_package.verifying_share())
+        {
+            bail!("Share for {} does not match its verifying share", name);
+        }
+
+        Self::check_threshold(&config, [&key_package])?;
+
+        let key_packages = BTreeMap::from([(id, key_package)]);
+        Ok(Self {
+            config,
+            key_packages: Arc::new(KeyMaterial(key_packages)),
+            public_key_package,
+            observer: None,
+        })
+    }
+
+    /// Run trusted dealer key generation without retaining every secret
+    /// Each participant's key package is handed to `sink` as soon as it is
+    /// derived, so no single map of all secrets outlives the call. Returns
+    /// the group's public key package; holders build their groups with
+    /// `new_for_participant`.
+    pub fn new_with_trusted_dealer_distributed(
+        config: &FrostGroupConfig,
+        rng: &mut (impl RngCore + CryptoRng),
+        mut sink: impl FnMut(Identifier, KeyPackage),
+    ) -> Result<PublicKeyPackage> {
+        check_rng(rng)?;
+        if config.is_single_signer() {
+            let (key_packages, public_key_package) =
+                Self::deal_single_signer(config, rng)?;
+            key_packages.into_iter().for_each(|(id, kp)| sink(id, kp));
+            return Ok(public_key_package);
+        }
+        let (secret_shares, public_key_package) =
+            frost::keys::generate_with_dealer(
+                config.max_signers() as u16,
+                config.min_signers() as u16,
+                frost::keys::IdentifierList::Custom(&config.participant_ids()),
+                rng,
+            )?;
+
+        // Each share is moved out of the dealer's map and consumed, so no
+        // second copy of any secret is made here
+        for (identifier, secret_share) in secret_shares {
+            sink(identifier, KeyPackage::try_from(secret_share)?);
+        }
+
+        Ok(public_key_package)
+    }
+
+    /// DKG round 1 for participant `name`
+    /// Keep the secret package private until `dkg_part2`; broadcast the
+    /// public package to every other participant. Both are serializable, so
+    /// they can be persisted between rounds.
+    pub fn dkg_part1(
+        config: &FrostGroupConfig,
+        name: &str,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<(dkg::round1::SecretPackage, dkg::round1::Package)> {
+        let id = Self::config_id(config, name)?;
+        Ok(dkg::part1(
+            id,
+            config.max_signers() as u16,
+            config.min_signers() as u16,
+            rng,
+        )?)
+    }
+
+    /// DKG round 2: consume this participant's round-1 secret and the round-1
+    /// packages received from every other participant
+    /// Returns the round-2 secret to keep and one package per recipient, to
+    /// be sent privately to that participant
+    pub fn dkg_part2(
+        config: &FrostGroupConfig,
+        secret_package: dkg::round1::SecretPackage,
+        round1_packages: &BTreeMap<Identifier, dkg::round1::Package>,
+    ) -> Result<(
+        dkg::round2::SecretPackage,
+        BTreeMap<Identifier, dkg::round2::Package>,
+    )> {
+        let id = *secret_package.identifier();
+        Self::check_dkg_peers(config, &id, round1_packages.keys())?;
+        Ok(dkg::part2(secret_package, round1_packages)?)
+    }
+
+    /// DKG round 3: finish key generation from this participant's round-2
+    /// secret, the round-1 packages, and the round-2 packages addressed to it
+    /// Returns a single-participant group, as `new_for_participant` would
+    pub fn dkg_part3(
+        config: FrostGroupConfig,
+        secret_package: &dkg::round2::SecretPackage,
+        round1_packages: &BTreeMap<Identifier, dkg::round1::Package>,
+        round2_packages: &BTreeMap<Identifier, dkg::round2::Package>,
+    ) -> Result<Self> {
+        let id = *secret_package.identifier();
+        Self::check_dkg_peers(&config, &id, round1_packages.keys())?;
+        Self::check_dkg_peers(&config, &id, round2_packages.keys())?;
+        let (key_package, public_key_package) =
+            dkg::part3(secret_package, round1_packages, round2_packages)?;
+        Self::new_for_participant(config, public_key_package, key_package)
     }
 
     /// Get the minimum number of signers required (threshold)
@@ -94,14 +327,112 @@ impl FrostGroup {
         self.config.participants().contains_key(name)
     }
 
-    /// Get the list of all participant names
+    /// Get the list of all participant names, sorted lexicographically
+    /// This is not the order names were passed to the config; use
+    /// `participant_names_by_id` when correlating names with identifiers or
+    /// shares
     pub fn participant_names(&self) -> Vec<String> {
         self.config.participants().keys().cloned().collect()
     }
 
+    /// Get the list of all participant names in identifier order (see
+    /// `FrostGroupConfig::participant_names_in_id_order`)
+    pub fn participant_names_by_id(&self) -> Vec<String> {
+        self.config.participant_names_in_id_order()
+    }
+
+    /// Get the names of participants whose key packages this instance holds
+    /// Unlike `participant_names`, this excludes configured participants
+    /// whose shares live elsewhere (e.g. a group built with `import_share`)
+    pub fn held_shares(&self) -> Vec<String> {
+        self.key_packages
+            .keys()
+            .map(|id| self.config.participant_name(id).to_string())
+            .collect()
+    }
+
+    /// Whether this instance holds any share, and so can take part in signing
+    /// False for a group decoded from `to_cbor`'s public form
+    pub fn can_sign(&self) -> bool { !self.key_packages.is_empty() }
+
+    /// Whether this instance holds every configured participant's share and
+    /// can therefore sign with any signer set on the co-located path
+    pub fn is_complete(&self) -> bool {
+        self.key_packages.len() == self.config.max_signers()
+    }
+
+    /// Get the names of configured participants whose key packages this
+    /// instance does not hold
+    pub fn missing_shares(&self) -> Vec<String> {
+        self.config
+            .participants()
+            .iter()
+            .filter(|(_, id)| !self.key_packages.contains_key(id))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Whether this instance and `other` can take part in one ceremony
+    /// True when both hold shares of the same group key under the same
+    /// threshold and participant identifiers, e.g. a full group and a holder
+    /// rebuilt with `import_share`. Lets a coordinator reject mismatched group
+    /// copies before a joint ceremony
+    pub fn can_cosign_with(&self, other: &FrostGroup) -> bool {
+        self.verifying_key() == other.verifying_key()
+            && self.min_signers() == other.min_signers()
+            && self.config.participants() == other.config.participants()
+    }
+
+    /// Install a callback for advisory warnings (see `GroupWarning`)
+    /// Clones of the group made afterwards share the callback. Warnings about
+    /// the group's own configuration, such as `SingleSigner`, are reported
+    /// as soon as the callback is installed
+    pub fn set_observer(
+        &mut self,
+        observer: impl Fn(&GroupWarning) + Send + Sync + 'static,
+    ) {
+        self.observer = Some(Observer::new(observer));
+        if self.config.is_single_signer() {
+            self.warn(GroupWarning::SingleSigner {
+                max_signers: self.config.max_signers(),
+            });
+        }
+    }
+
+    /// Report a warning to the observer, if any
+    fn warn(&self, warning: GroupWarning) {
+        if let Some(observer) = &self.observer {
+            observer.warn(&warning);
+        }
+    }
+
     /// Get a reference to the group configuration
+    /// The threshold, roster, and charter are fixed for the group's lifetime;
+    /// change them through `reconfigure`. No `&mut` path reaches the config
+    /// of a keyed group:
+    ///
+    /// ```compile_fail,E0596
+    /// # fn edit(group: frost_pm_test::FrostGroup) -> anyhow::Result<()> {
+    /// group.config().add_participant("Erin")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail,E0599
+    /// # fn edit(mut group: frost_pm_test::FrostGroup) -> anyhow::Result<()> {
+    /// group.set_charter("A different purpose".to_string())?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn config(&self) -> &FrostGroupConfig { &self.config }
 
+    /// Discard this group's key material and start editing its parameters
+    /// The builder's config must be keyed afresh with
+    /// `new_with_trusted_dealer` or a DKG run
+    pub fn reconfigure(self) -> FrostGroupConfigBuilder {
+        self.config.reconfigure()
+    }
+
     /// Get a reference to a participant's key package by name
     pub fn key_package(&self, name: &str) -> Result<&KeyPackage> {
         let id = self.name_to_id(name)?;
@@ -110,6 +441,102 @@ impl FrostGroup {
             .ok_or_else(|| anyhow!("No key package for participant {}", name))
     }
 
+    /// Export one participant's key package as a checksummed envelope
+    /// Envelopes are per-participant so each share can be handed to its holder
+    pub fn export_share(&self, name: &str) -> Result<ShareEnvelope> {
+        ShareEnvelope::seal(name, self.key_package(name)?)
+    }
+
+    /// Encode the group as CBOR, with every share only if `include_secrets`
+    /// The map's `"form"` tag is `"public"` (the public summary and public
+    /// key package, for verifiers) or `"full"` (adding every key package and
+    /// `FROST_KEY_FORMAT`, for backup). The full form holds secrets in the
+    /// clear, so encrypt it before storage; it needs a complete group
+    pub fn to_cbor(&self, include_secrets: bool) -> Result<CBOR> {
+        let mut map = Map::new();
+        map.insert("summary", CBOR::from(&self.public_summary()));
+        map.insert(
+            "public_key_package",
+            CBOR::to_byte_string(self.public_key_package.serialize()?),
+        );
+        if !include_secrets {
+            map.insert("form", "public");
+            return Ok(map.into());
+        }
+        if !self.is_complete() {
+            bail!(
+                "cannot serialize secrets, missing shares for {}",
+                self.missing_shares().join(", ")
+            );
+        }
+        let mut key_packages = Vec::new();
+        for key_package in self.key_packages.values() {
+            key_packages.push(CBOR::to_byte_string(key_package.serialize()?));
+        }
+        map.insert("form", "full");
+        map.insert("format", FROST_KEY_FORMAT);
+        map.insert("key_packages", key_packages);
+        Ok(map.into())
+    }
+
+    /// Decode a group written by `to_cbor`, revalidating its key material
+    /// A public-form group verifies but holds no shares, so any local signing
+    /// fails with `FrostError::PublicOnlyGroup` (see `can_sign`). As with
+    /// `PublicGroupSummary`, local config settings such as display names are
+    /// not encoded
+    pub fn from_cbor(cbor: CBOR) -> Result<Self> {
+        let map = cbor.try_into_map()?;
+        let field = |key: &str| {
+            map.get::<_, CBOR>(key)
+                .ok_or_else(|| anyhow!("encoded group is missing {}", key))
+        };
+        let form = field("form")?.try_into_text()?;
+        let summary = PublicGroupSummary::try_from(field("summary")?)?;
+        let public_key_package = PublicKeyPackage::deserialize(
+            &field("public_key_package")?.try_into_byte_string()?,
+        )?;
+        if public_key_package.verifying_key() != summary.verifying_key() {
+            bail!("encoded group's key package does not match its summary");
+        }
+        let config = summary.config().clone();
+        match form.as_str() {
+            "public" if map.len() == 3 => {
+                let mut ids = config.participant_ids();
+                ids.sort();
+                if !public_key_package.verifying_shares().keys().eq(&ids) {
+                    bail!("encoded group's shares do not match its roster");
+                }
+                Ok(Self {
+                    config,
+                    key_packages: Arc::new(KeyMaterial(BTreeMap::new())),
+                    public_key_package,
+                    observer: None,
+                })
+            }
+            "full" if map.len() == 5 => {
+                let format = field("format")?.try_into_text()?;
+                if format != FROST_KEY_FORMAT {
+                    bail!(FrostError::UnsupportedKeyVersion {
+                        found: format,
+                        expected: FROST_KEY_FORMAT.to_string(),
+                    });
+                }
+                let mut key_packages = BTreeMap::new();
+                for bytes in field("key_packages")?.try_into_array()? {
+                    let bytes = Zeroizing::new(bytes.try_into_byte_string()?);
+                    let key_package = KeyPackage::deserialize(&bytes)?;
+                    key_packages.insert(*key_package.identifier(), key_package);
+                }
+                Self::new_from_key_material(
+                    config,
+                    key_packages,
+                    public_key_package,
+                )
+            }
+            _ => bail!("unknown encoded group form: {}", form),
+        }
+    }
+
     /// Get the public key package for this group
     pub fn public_key_package(&self) -> &PublicKeyPackage {
         &self.public_key_package
@@ -120,9 +547,123 @@ impl FrostGroup {
         self.public_key_package.verifying_key()
     }
 
-    /// Verify a signature against a message using the group's public key
+    /// Verify a signature made with `round_2_sign_prehashed`
+    pub fn verify_prehashed(
+        &self,
+        digest: &[u8; 32],
+        signature: &Signature,
+    ) -> Result<()> {
+        self.verify_as(MessagePurpose::Prehashed, digest, signature)
+    }
+
+    /// Verify a signature made with `round_2_sign_with_aad`
+    /// Only succeeds when `aad` is exactly the associated data signed
+    pub fn verify_with_aad(
+        &self,
+        aad: &[u8],
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<()> {
+        self.verify_as(
+            MessagePurpose::Aad,
+            &aad_message(aad, message)?,
+            signature,
+        )
+    }
+
+    /// Export the group's public material for verifiers that must not hold
+    /// any shares
+    pub fn public_summary(&self) -> PublicGroupSummary {
+        PublicGroupSummary::new(self.config.clone(), *self.verifying_key())
+    }
+
+    /// Have `signers` sign the group's own public summary
+    /// Runs both rounds locally, so every signer's share must be held here;
+    /// see `public_summary::verify_attestation`
+    pub fn self_attest(
+        &self,
+        signers: &[&str],
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<GroupAttestation> {
+        let summary = self.public_summary();
+        let (commitments, nonces) = self.round_1_commit(signers, rng)?;
+        let signature = self.round_2_sign_as(
+            MessagePurpose::Attestation,
+            signers,
+            &commitments,
+            &nonces,
+            &summary.attestation_message()?,
+        )?;
+        Ok(GroupAttestation::new(summary, signature))
+    }
+
+    /// Time `iterations` full co-located signing ceremonies
+    /// A diagnostic for sizing hardware: each ceremony runs both rounds and
+    /// aggregation for a threshold of signers (the first `min_signers` in
+    /// identifier order), whose shares must all be held here
+    pub fn benchmark_sign(
+        &self,
+        iterations: usize,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<BenchResult> {
+        if iterations == 0 {
+            bail!("benchmark needs at least one iteration");
+        }
+        let names = self.config.participant_names_in_id_order();
+        let signers: Vec<&str> = names
+            .iter()
+            .take(self.min_signers())
+            .map(String::as_str)
+            .collect();
+
+        let mut latencies = Vec::with_capacity(iterations);
+        let start = Instant::now();
+        for _ in 0..iterations {
+            let ceremony_start = Instant::now();
+            let (commitments, nonces) = self.round_1_commit(&signers, rng)?;
+            self.round_2_sign(
+                &signers,
+                &commitments,
+                &nonces,
+                b"FROST signing benchmark",
+            )?;
+            latencies.push(ceremony_start.elapsed());
+        }
+        Ok(BenchResult::from_latencies(latencies, start.elapsed()))
+    }
+
+    /// Get a stable fingerprint identifying this group
+    /// SHA-256 over a domain tag and the group's verifying key, so every
+    /// holder of the group, partial or complete, computes the same value
+    pub fn fingerprint(&self) -> [u8; 32] {
+        key_fingerprint(self.verifying_key())
+    }
+
+    /// Verify a signature against an application message using the group's
+    /// public key
     pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<()> {
-        Ok(self.verifying_key().verify(message, signature)?)
+        self.verify_as(MessagePurpose::Application, message, signature)
+    }
+
+    /// Verify a signature made with `round_2_sign_as` for `purpose`
+    pub fn verify_as(
+        &self,
+        purpose: MessagePurpose,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<()> {
+        let framed = framed_message(purpose, message);
+        Ok(self.verifying_key().verify(&framed, signature)?)
+    }
+
+    /// Verify a signature received as raw bytes, e.g. over the wire
+    /// Bytes that do not parse as a signature fail with
+    /// `FrostError::MalformedSignature` rather than a verification error
+    pub fn verify_bytes(&self, message: &[u8], signature: &[u8]) -> Result<()> {
+        let Ok(signature) = Signature::deserialize(signature) else {
+            bail!(FrostError::MalformedSignature { len: signature.len() });
+        };
+        self.verify(message, &signature)
     }
 
     /// Round-1 only: collect commitments for two-ceremony approach
@@ -145,10 +686,8 @@ impl FrostGroup {
             );
         }
 
-        // Validate all signer names exist upfront
-        for &signer_name in signers {
-            self.key_package(signer_name)?; // This validates the name exists
-        }
+        // Validate all signer names exist and are held locally upfront
+        self.ensure_local_shares(signers)?;
 
         let mut commitments_map: BTreeMap<Identifier, SigningCommitments> =
             BTreeMap::new();
@@ -165,15 +704,290 @@ impl FrostGroup {
         Ok((commitments_map, nonces_map))
     }
 
-    /// Round-2: replay commitments and perform signing
+    /// Same as `round_1_commit`, but with signers addressed by identifier and
+    /// nonces keyed by identifier rather than name
+    /// For the distributed path, where nonces are routed back to participant
+    /// processes that only know their identifier
+    pub fn round_1_commit_by_id(
+        &self,
+        signers: &[Identifier],
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<(
+        BTreeMap<Identifier, SigningCommitments>,
+        BTreeMap<Identifier, SigningNonces>,
+    )> {
+        let mut names = Vec::with_capacity(signers.len());
+        for id in signers {
+            if !self.config.participant_ids().contains(id) {
+                bail!("Unknown participant identifier");
+            }
+            names.push(self.config.participant_name(id));
+        }
+        let (commitments_map, nonces_map) = self.round_1_commit(&names, rng)?;
+        let nonces_map = nonces_map
+            .into_iter()
+            .map(|(name, nonces)| Ok((self.name_to_id(&name)?, nonces)))
+            .collect::<Result<_>>()?;
+        Ok((commitments_map, nonces_map))
+    }
+
+    /// Round-2 over a payload digest from `message::prehash`
+    /// Signs the digest under `MessagePurpose::Prehashed`, so arbitrarily
+    /// large payloads never pass through the signers and the signature never
+    /// verifies as an application message
+    pub fn round_2_sign_prehashed(
+        &self,
+        signers: &[&str],
+        commitments_map: &BTreeMap<Identifier, SigningCommitments>,
+        nonces_map: &BTreeMap<String, SigningNonces>,
+        digest: &[u8; 32],
+    ) -> Result<Signature> {
+        self.round_2_sign_as(
+            MessagePurpose::Prehashed,
+            signers,
+            commitments_map,
+            nonces_map,
+            digest,
+        )
+    }
+
+    /// Round-2 over an application message with associated data
+    /// Signs `message::aad_message(aad, message)` under `MessagePurpose::Aad`,
+    /// so it never verifies as a plain application message; verifiers must
+    /// supply the same `aad` to `verify_with_aad`. Unlike a mark's info, the
+    /// AAD is never stored in a mark
+    pub fn round_2_sign_with_aad(
+        &self,
+        signers: &[&str],
+        commitments_map: &BTreeMap<Identifier, SigningCommitments>,
+        nonces_map: &BTreeMap<String, SigningNonces>,
+        aad: &[u8],
+        message: &[u8],
+    ) -> Result<Signature> {
+        self.round_2_sign_as(
+            MessagePurpose::Aad,
+            signers,
+            commitments_map,
+            nonces_map,
+            &aad_message(aad, message)?,
+        )
+    }
+
+    /// Run both signing rounds for an application message, returning the
+    /// signature with the Round-1 commitments it was made from
+    /// Commitments are public, so they can be published alongside the
+    /// signature (e.g. to a transparency log); third parties confirm with
+    /// `PublicGroupSummary::verify_with_package` that exactly these
+    /// commitments produced the signature
+    pub fn sign_with_commitments(
+        &self,
+        message: &[u8],
+        signers: &[&str],
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<(Signature, BTreeMap<Identifier, SigningCommitments>)> {
+        let (commitments, nonces) = self.round_1_commit(signers, rng)?;
+        let signature =
+            self.round_2_sign(signers, &commitments, &nonces, message)?;
+        Ok((signature, commitments))
+    }
+
+    /// Sign `message` with every minimal (threshold-sized) signer subset
+    /// A health diagnostic confirming every quorum can produce a signature
+    /// under the group key. Subsets are in lexicographic name order; there are
+    /// C(max_signers, min_signers) of them, so this is only practical for
+    /// small groups. Requires every share to be held locally
+    pub fn sign_all_subsets(
+        &self,
+        message: &[u8],
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<Vec<(Vec<String>, Signature)>> {
+        let names = self.participant_names();
+        let mut results = Vec::new();
+        for subset in combinations(&names, self.min_signers()) {
+            let signers: Vec<&str> =
+                subset.iter().map(|name| name.as_str()).collect();
+            let (commitments, nonces) = self.round_1_commit(&signers, rng)?;
+            let signature =
+                self.round_2_sign(&signers, &commitments, &nonces, message)?;
+            results.push((subset, signature));
+        }
+        Ok(results)
+    }
+
+    /// Suggest signer sets whose summed weight (see
+    /// `FrostGroupConfig::participant_weight`) reaches `target`
+    /// Weights are advisory: every set still has at least `min_signers`
+    /// members, since FROST cannot sign with fewer. Only minimal sets are
+    /// returned: dropping any member falls below `target` or the threshold.
+    /// Sets are ordered by size, then lexicographically by name. Every
+    /// subset is considered, so this suits small groups only.
+    pub fn participants_meeting_weight(
+        &self,
+        target: usize,
+    ) -> Vec<Vec<String>> {
+        let names = self.participant_names();
+        let weight = |name: &String| self.config.participant_weight(name);
+        let min = self.min_signers();
+        let mut result = Vec::new();
+        for k in min..=names.len() {
+            for subset in combinations(&names, k) {
+                let total: usize = subset.iter().map(weight).sum();
+                let lightest = subset.iter().map(weight).min().unwrap_or(0);
+                let minimal = k == min || total - lightest < target;
+                if total >= target && minimal {
+                    result.push(subset);
+                }
+            }
+        }
+        result
+    }
+
+    /// Run both signing rounds on a worker thread, giving up after `timeout`
+    /// Guards the co-located path against an RNG that blocks (e.g. a slow
+    /// HSM-backed source) and raises `FrostError::Timeout` when exceeded. The
+    /// worker cannot be cancelled; it runs to completion in the background and
+    /// its result is discarded.
+    pub fn sign_with_timeout<R>(
+        &self,
+        message: &[u8],
+        signers: &[&str],
+        rng: R,
+        timeout: Duration,
+    ) -> Result<Signature>
+    where
+        R: RngCore + CryptoRng + Send + 'static,
+    {
+        let rx = self.spawn_signing_worker(message, signers, rng, None);
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match rx.recv_timeout(remaining) {
+                Ok(WorkerEvent::Committed(_)) => {}
+                Ok(WorkerEvent::Finished(result)) => return result,
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    bail!(FrostError::Timeout { limit: timeout })
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    bail!("signing worker terminated without a result")
+                }
+            }
+        }
+    }
+
+    /// Run both signing rounds on a worker thread until done or `token` is
+    /// cancelled
+    /// Cancellation raises `FrostError::Cancelled`, listing who had committed.
+    /// The worker checks `token` between the rounds and drops its nonces
+    /// unused, so once cancelled it produces no signature shares; a Round 2
+    /// already under way finishes in the background and is discarded.
+    pub fn sign_cancellable<R>(
+        &self,
+        message: &[u8],
+        signers: &[&str],
+        rng: R,
+        token: &CancellationToken,
+    ) -> Result<Signature>
+    where
+        R: RngCore + CryptoRng + Send + 'static,
+    {
+        let rx = self.spawn_signing_worker(
+            message,
+            signers,
+            rng,
+            Some(token.clone()),
+        );
+        let mut committed = Vec::new();
+        loop {
+            if token.is_cancelled() {
+                bail!(FrostError::Cancelled { committed });
+            }
+            match rx.recv_timeout(CANCELLATION_POLL_INTERVAL) {
+                Ok(WorkerEvent::Committed(ids)) => committed = ids,
+                Ok(WorkerEvent::Finished(result)) => return result,
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    bail!("signing worker terminated without a result")
+                }
+            }
+        }
+    }
+
+    /// Run both signing rounds on a new thread, reporting its progress
+    /// Round 2 is skipped if `token` is cancelled once Round 1 returns.
+    fn spawn_signing_worker<R>(
+        &self,
+        message: &[u8],
+        signers: &[&str],
+        mut rng: R,
+        token: Option<CancellationToken>,
+    ) -> mpsc::Receiver<WorkerEvent>
+    where
+        R: RngCore + CryptoRng + Send + 'static,
+    {
+        let group = self.clone();
+        let message = message.to_vec();
+        let signers: Vec<String> =
+            signers.iter().map(|s| s.to_string()).collect();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let signers: Vec<&str> =
+                signers.iter().map(String::as_str).collect();
+            let result = group.round_1_commit(&signers, &mut rng).and_then(
+                |(commitments, nonces)| {
+                    let ids: Vec<_> = commitments.keys().copied().collect();
+                    // Sends fail only once the caller has stopped waiting
+                    let _ = tx.send(WorkerEvent::Committed(ids.clone()));
+                    let cancelled =
+                        token.as_ref().is_some_and(|t| t.is_cancelled());
+                    if cancelled {
+                        // Never sign for a caller that has given up
+                        drop(nonces);
+                        bail!(FrostError::Cancelled { committed: ids });
+                    }
+                    group.round_2_sign(
+                        &signers,
+                        &commitments,
+                        &nonces,
+                        &message,
+                    )
+                },
+            );
+            let _ = tx.send(WorkerEvent::Finished(result));
+        });
+        rx
+    }
+
+    /// Round-2: replay commitments and sign an application message
     /// Requires the same commitments from Round-1 and the nonces kept by
-    /// participants
+    /// participants. Messages are held in memory and hashed by every signer;
+    /// beyond `PREHASH_RECOMMENDED_LEN` bytes a `GroupWarning::LargeMessage`
+    /// is reported, and large payloads should use `round_2_sign_prehashed`
     pub fn round_2_sign(
         &self,
         signers: &[&str],
         commitments_map: &BTreeMap<Identifier, SigningCommitments>,
         nonces_map: &BTreeMap<String, SigningNonces>,
         message: &[u8],
+    ) -> Result<Signature> {
+        self.round_2_sign_as(
+            MessagePurpose::Application,
+            signers,
+            commitments_map,
+            nonces_map,
+            message,
+        )
+    }
+
+    /// Round-2 for a message of the given purpose
+    /// Chain messages must be signed as `MessagePurpose::Genesis` or
+    /// `MessagePurpose::Mark`; the chain verifies them only as such
+    pub fn round_2_sign_as(
+        &self,
+        purpose: MessagePurpose,
+        signers: &[&str],
+        commitments_map: &BTreeMap<Identifier, SigningCommitments>,
+        nonces_map: &BTreeMap<String, SigningNonces>,
+        message: &[u8],
     ) -> Result<Signature> {
         if signers.len() < self.config.min_signers() {
             bail!(
@@ -183,32 +997,188 @@ impl FrostGroup {
             );
         }
 
+        self.ensure_local_shares(signers)?;
+        if message.len() > PREHASH_RECOMMENDED_LEN {
+            self.warn(GroupWarning::LargeMessage {
+                len: message.len(),
+                threshold: PREHASH_RECOMMENDED_LEN,
+            });
+        }
+
         // Create signing package from the commitments
         let signing_package =
-            SigningPackage::new(commitments_map.clone(), message);
+            Self::signing_package(purpose, commitments_map, message);
 
         // Round 2: Generate signature shares
         let mut signature_shares: BTreeMap<Identifier, SignatureShare> =
             BTreeMap::new();
         for &signer_name in signers {
             let signer_id = self.name_to_id(signer_name)?;
-            let nonces = &nonces_map[signer_name];
+            let nonces = nonces_map.get(signer_name).ok_or_else(|| {
+                anyhow!("No nonces for participant {}", signer_name)
+            })?;
             let signature_share = self.sign_for_participant(
                 signer_name,
                 &signing_package,
                 nonces,
             )?;
+            #[cfg(fuzzing)]
+            let signature_share =
+                crate::fuzzing::tamper_share(signature_share)?;
             signature_shares.insert(signer_id, signature_share);
         }
 
-        // Aggregate signature
-        let group_signature = frost::aggregate(
+        // Aggregate signature, naming any faulty signers
+        self.aggregate_with_identifiable_abort(
             &signing_package,
             &signature_shares,
+        )
+    }
+
+    /// Round-2 over a threshold subset of the Round-1 participants
+    /// Picks the first `min_signers` of `available_signers` that have both a
+    /// commitment and nonces, so a participant who committed in Round-1 but
+    /// dropped out before Round-2 does not force a restart
+    pub fn round_2_sign_subset(
+        &self,
+        available_signers: &[&str],
+        commitments_map: &BTreeMap<Identifier, SigningCommitments>,
+        nonces_map: &BTreeMap<String, SigningNonces>,
+        message: &[u8],
+    ) -> Result<Signature> {
+        let mut signers = Vec::with_capacity(self.config.min_signers());
+        let mut subset_commitments = BTreeMap::new();
+        for &signer_name in available_signers {
+            if signers.len() == self.config.min_signers() {
+                break;
+            }
+            let signer_id = self.name_to_id(signer_name)?;
+            if let Some(commitments) = commitments_map.get(&signer_id)
+                && nonces_map.contains_key(signer_name)
+            {
+                signers.push(signer_name);
+                subset_commitments.insert(signer_id, *commitments);
+            }
+        }
+
+        // Too few responders: report who got how far so the caller can retry
+        // with a different subset
+        if signers.len() < self.config.min_signers() {
+            bail!(FrostError::CeremonyAborted {
+                committed: commitments_map.keys().cloned().collect(),
+                signed: subset_commitments.keys().cloned().collect(),
+                reason: format!(
+                    "need {} signers with commitments and nonces, have {}",
+                    self.config.min_signers(),
+                    signers.len()
+                ),
+            });
+        }
+
+        self.round_2_sign(&signers, &subset_commitments, nonces_map, message)
+    }
+
+    /// Build the signing package for `message` under `purpose`
+    /// Coordinators in distributed mode must use this rather than
+    /// `SigningPackage::new`, so the purpose tag is signed
+    pub fn signing_package(
+        purpose: MessagePurpose,
+        commitments_map: &BTreeMap<Identifier, SigningCommitments>,
+        message: &[u8],
+    ) -> SigningPackage {
+        SigningPackage::new(
+            commitments_map.clone(),
+            &framed_message(purpose, message),
+        )
+    }
+
+    /// Verify one participant's signature share over `message` under
+    /// `purpose` before aggregation
+    /// Lets a coordinator in distributed mode reject a faulty share as soon as
+    /// it arrives instead of failing only at aggregation
+    pub fn verify_share_against_message(
+        &self,
+        purpose: MessagePurpose,
+        name: &str,
+        commitments_map: &BTreeMap<Identifier, SigningCommitments>,
+        message: &[u8],
+        share: &SignatureShare,
+    ) -> Result<()> {
+        let id = self.name_to_id(name)?;
+        if !commitments_map.contains_key(&id) {
+            bail!("No commitment from participant {}", name);
+        }
+        let signing_package =
+            Self::signing_package(purpose, commitments_map, message);
+        self.verify_share(id, share, &signing_package).map_err(|e| {
+            anyhow!("Invalid signature share from {}: {}", name, e)
+        })
+    }
+
+    /// Verify published signature shares in bulk, e.g. every share in a
+    /// transparency log of ceremony transcripts, returning each item's
+    /// signer and result in input order
+    /// A share fails if its signer has no verifying share in this group or no
+    /// commitment in its signing package
+    pub fn verify_shares_batch(
+        &self,
+        items: &[(Identifier, &SigningPackage, &SignatureShare)],
+    ) -> Vec<(Identifier, bool)> {
+        items
+            .iter()
+            .map(|&(id, signing_package, share)| {
+                (id, self.verify_share(id, share, signing_package).is_ok())
+            })
+            .collect()
+    }
+
+    /// Aggregate signature shares, identifying every faulty signer on failure
+    /// If frost reports an invalid share, each share is verified individually
+    /// and the error is a `FrostError::InvalidSignatureShares` naming all
+    /// participants whose shares failed, not just the first
+    pub fn aggregate_with_identifiable_abort(
+        &self,
+        signing_package: &SigningPackage,
+        signature_shares: &BTreeMap<Identifier, SignatureShare>,
+    ) -> Result<Signature> {
+        match frost::aggregate(
+            signing_package,
+            signature_shares,
             &self.public_key_package,
-        )?;
+        ) {
+            Ok(signature) => Self::check_canonical(signature),
+            Err(frost::Error::InvalidSignatureShare { .. }) => {
+                let culprits = self
+                    .invalid_share_signers(signing_package, signature_shares);
+                bail!(FrostError::InvalidSignatureShares { culprits })
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Aggregate signature shares into a group signature
+    /// Used by the coordinator in distributed mode, where each participant
+    /// produces its share independently
+    pub fn aggregate(
+        &self,
+        signing_package: &SigningPackage,
+        signature_shares: &BTreeMap<Identifier, SignatureShare>,
+    ) -> Result<Signature> {
+        Self::check_canonical(frost::aggregate(
+            signing_package,
+            signature_shares,
+            &self.public_key_package,
+        )?)
+    }
 
-        Ok(group_signature)
+    /// Check that a signature reparses to itself from its serialization, so
+    /// an archived copy verifies exactly as the original did
+    fn check_canonical(signature: Signature) -> Result<Signature> {
+        let reparsed = Signature::deserialize(&signature.serialize()?);
+        if reparsed.ok() != Some(signature) {
+            bail!(FrostError::NonCanonicalSignature);
+        }
+        Ok(signature)
     }
 }
 
@@ -222,8 +1192,168 @@ impl FrostGroup {
             .ok_or_else(|| anyhow!("Unknown participant: {}", name))
     }
 
-    /// Helper method to perform round1 commit for a participant by name
-    fn commit_for_participant(
+    /// Look up a participant's identifier in a config, before a group exists
+    fn config_id(config: &FrostGroupConfig, name: &str) -> Result<Identifier> {
+        config
+            .participants()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| anyhow!("Unknown participant: {}", name))
+    }
+
+    /// Check that DKG packages came from exactly every participant other than
+    /// `id`
+    fn check_dkg_peers<'a>(
+        config: &FrostGroupConfig,
+        id: &Identifier,
+        senders: impl Iterator<Item = &'a Identifier>,
+    ) -> Result<()> {
+        if !config.participant_ids().contains(id) {
+            bail!("DKG package is not for a configured participant");
+        }
+        let mut expected = config.participant_ids();
+        expected.retain(|peer| peer != id);
+        expected.sort();
+        if !senders.eq(expected.iter()) {
+            bail!(
+                "{} needs DKG packages from each of the {} other participants",
+                config.participant_name(id),
+                expected.len()
+            );
+        }
+        Ok(())
+    }
+
+    /// Re-check the threshold at group construction rather than trusting
+    /// config validation: an empty signer set must never satisfy it, and every
+    /// key package must have been generated for the same threshold
+    fn check_threshold<'a>(
+        config: &FrostGroupConfig,
+        key_packages: impl IntoIterator<Item = &'a KeyPackage>,
+    ) -> Result<()> {
+        let (min, max) = (config.min_signers(), config.max_signers());
+        if min == 0 || min > max {
+            bail!("invalid threshold: {} of {} signers", min, max);
+        }
+        for key_package in key_packages {
+            let key_min = *key_package.min_signers() as usize;
+            if key_min != min {
+                bail!(
+                    "key package for {} has threshold {}, config requires {}",
+                    config.participant_name(key_package.identifier()),
+                    key_min,
+                    min
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that each key package belongs to `public_key_package` and that
+    /// its verifying shares interpolate to the group verifying key
+    /// Only public values are combined, so the group signing key is never
+    /// materialized; sliding windows of `min_signers` shares cover every
+    /// verifying share in the package.
+    fn check_key_material(
+        config: &FrostGroupConfig,
+        key_packages: &BTreeMap<Identifier, KeyPackage>,
+        public_key_package: &PublicKeyPackage,
+    ) -> Result<()> {
+        for (id, key_package) in key_packages {
+            let expected = public_key_package.verifying_shares().get(id);
+            let derived = VerifyingShare::from(*key_package.signing_share());
+            if key_package.verifying_key() != public_key_package.verifying_key()
+                || expected != Some(key_package.verifying_share())
+                || expected != Some(&derived)
+            {
+                bail!(FrostError::KeyMaterialMismatch {
+                    participant: config.participant_name(id).to_string(),
+                });
+            }
+        }
+        let shares: Vec<_> =
+            public_key_package.verifying_shares().iter().collect();
+        let min = config.min_signers();
+        if shares.len() < min {
+            bail!("public key package has fewer than {} verifying shares", min);
+        }
+        let group_key = public_key_package.verifying_key().to_element();
+        for window in shares.windows(min) {
+            let ids: BTreeSet<Identifier> =
+                window.iter().map(|(id, _)| **id).collect();
+            let mut interpolated = Ed25519Group::identity();
+            for (id, share) in window {
+                let lambda =
+                    frost_core::compute_lagrange_coefficient(&ids, None, **id)?;
+                interpolated += share.to_element() * lambda;
+            }
+            if interpolated != group_key {
+                bail!(FrostError::VerifyingSharesMismatch);
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every signer is a participant whose share this instance
+    /// holds, naming all missing shares at once for the co-located path
+    fn ensure_local_shares(&self, signers: &[&str]) -> Result<()> {
+        if !self.can_sign() {
+            bail!(FrostError::PublicOnlyGroup);
+        }
+        let mut missing = Vec::new();
+        for &name in signers {
+            if !self.key_packages.contains_key(&self.name_to_id(name)?) {
+                missing.push(name);
+            }
+        }
+        if !missing.is_empty() {
+            bail!(
+                "cannot sign locally, missing shares for {}",
+                missing.join(", ")
+            );
+        }
+        Ok(())
+    }
+
+    /// Verify each signature share and return the names of the participants
+    /// whose shares are invalid
+    fn invalid_share_signers(
+        &self,
+        signing_package: &SigningPackage,
+        signature_shares: &BTreeMap<Identifier, SignatureShare>,
+    ) -> Vec<String> {
+        signature_shares
+            .iter()
+            .filter(|(id, share)| {
+                self.verify_share(**id, share, signing_package).is_err()
+            })
+            .map(|(id, _)| self.config.participant_name(id).to_string())
+            .collect()
+    }
+
+    /// Verify a single signature share against a signing package
+    fn verify_share(
+        &self,
+        id: Identifier,
+        share: &SignatureShare,
+        signing_package: &SigningPackage,
+    ) -> Result<()> {
+        let verifying_share = self
+            .public_key_package
+            .verifying_shares()
+            .get(&id)
+            .ok_or_else(|| anyhow!("No verifying share for identifier"))?;
+        Ok(frost_core::verify_signature_share(
+            id,
+            verifying_share,
+            share,
+            signing_package,
+            self.verifying_key(),
+        )?)
+    }
+
+    /// Perform round1 commit for a single participant by name
+    pub fn commit_for_participant(
         &self,
         participant_name: &str,
         rng: &mut (impl RngCore + CryptoRng),
@@ -232,8 +1362,8 @@ impl FrostGroup {
         Ok(frost::round1::commit(key_package.signing_share(), rng))
     }
 
-    /// Helper method to perform round2 signing for a participant by name
-    fn sign_for_participant(
+    /// Perform round2 signing for a single participant by name
+    pub fn sign_for_participant(
         &self,
         participant_name: &str,
         signing_package: &SigningPackage,
@@ -243,3 +1373,71 @@ impl FrostGroup {
         Ok(frost::round2::sign(signing_package, nonces, key_package)?)
     }
 }
+
+/// Participants' key packages, shared by every clone of a group and
+/// zeroized when the last clone drops
+#[derive(Debug)]
+struct KeyMaterial(BTreeMap<Identifier, KeyPackage>);
+
+impl Deref for KeyMaterial {
+    type Target = BTreeMap<Identifier, KeyPackage>;
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl Drop for KeyMaterial {
+    fn drop(&mut self) {
+        for key_package in self.0.values_mut() {
+            key_package.zeroize();
+        }
+    }
+}
+
+/// All `k`-element subsets of `items`, preserving their order
+fn combinations(items: &[String], k: usize) -> Vec<Vec<String>> {
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+    let mut result = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        for mut rest in combinations(&items[i + 1..], k - 1) {
+            rest.insert(0, item.clone());
+            result.push(rest);
+        }
+    }
+    result
+}
+
+/// Latency and throughput of `FrostGroup::benchmark_sign`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchResult {
+    /// Number of ceremonies run
+    pub iterations: usize,
+    /// Mean ceremony latency
+    pub mean: Duration,
+    /// Median ceremony latency
+    pub median: Duration,
+    /// 99th-percentile ceremony latency
+    pub p99: Duration,
+    /// Completed ceremonies per second of wall-clock time
+    pub signatures_per_second: f64,
+}
+
+impl BenchResult {
+    /// Summarize per-ceremony latencies and the total elapsed time
+    fn from_latencies(mut latencies: Vec<Duration>, elapsed: Duration) -> Self {
+        latencies.sort();
+        let iterations = latencies.len();
+        // Nearest-rank percentile over the sorted latencies
+        let percentile = |pct: usize| {
+            latencies[(iterations * pct).div_ceil(100).max(1) - 1]
+        };
+        Self {
+            iterations,
+            mean: latencies.iter().sum::<Duration>() / iterations as u32,
+            median: percentile(50),
+            p99: percentile(99),
+            signatures_per_second: iterations as f64 / elapsed.as_secs_f64(),
+        }
+    }
+}
@@ -1,10 +1,58 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
 
 use anyhow::{Result, bail};
+use bc_crypto::sha256;
 use frost_ed25519::Identifier;
+use provenance_mark::ProvenanceMarkResolution;
+
+use crate::{FrostError, message::length_prefix};
+
+/// Default upper bound on a charter's length in bytes
+pub const DEFAULT_MAX_CHARTER_LEN: usize = 4096;
+
+/// A policy validating each participant name when building a config
+/// Implement this to enforce organizational naming conventions; any
+/// `Fn(&str) -> Result<()>` closure is also a policy
+pub trait NamePolicy {
+    /// Return an error if `name` is not acceptable
+    fn validate(&self, name: &str) -> Result<()>;
+}
+
+impl<F: Fn(&str) -> Result<()>> NamePolicy for F {
+    fn validate(&self, name: &str) -> Result<()> { self(name) }
+}
+
+/// Rejects names conventionally used for adversaries in security protocols
+/// ("Eve", "Mallory", "Trudy"), which are confusing as real participant names
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReservedNames;
+
+impl ReservedNames {
+    /// Names rejected by this policy (compared case-insensitively)
+    pub const NAMES: [&'static str; 3] = ["Eve", "Mallory", "Trudy"];
+}
+
+impl NamePolicy for ReservedNames {
+    fn validate(&self, name: &str) -> Result<()> {
+        if Self::NAMES.iter().any(|r| r.eq_ignore_ascii_case(name)) {
+            bail!("participant name \"{}\" is reserved", name);
+        }
+        Ok(())
+    }
+}
 
 /// Configuration for the FROST group parameters
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "FrostGroupConfigWire", into = "FrostGroupConfigWire")
+)]
 pub struct FrostGroupConfig {
     /// Minimum number of signers required (threshold)
     min_signers: usize,
@@ -14,6 +62,16 @@ pub struct FrostGroupConfig {
     id_to_name: BTreeMap<Identifier, String>,
     /// Charter describing the purpose of this group
     charter: String,
+    /// Maximum charter length in bytes
+    max_charter_len: usize,
+    /// Lowest resolution a chain may be created at, if any
+    minimum_resolution: Option<ProvenanceMarkResolution>,
+    /// Identifiers set aside for future participants, in assignment order
+    reserved_slots: Vec<Identifier>,
+    /// Presentation names keyed by protocol name; never signed
+    display_names: BTreeMap<String, String>,
+    /// Quorum-planning weights keyed by protocol name; absent means 1
+    weights: BTreeMap<String, usize>,
 }
 
 impl FrostGroupConfig {
@@ -24,36 +82,338 @@ impl FrostGroupConfig {
         min_signers: usize,
         participant_names: &[&'static str],
         charter: String,
+    ) -> Result<Self> {
+        Self::from_names(min_signers, participant_names, charter)
+    }
+
+    /// Build a config with sequential identifiers from names in order
+    fn from_names(
+        min_signers: usize,
+        participant_names: &[impl AsRef<str>],
+        charter: String,
     ) -> Result<Self> {
         let max_signers = participant_names.len();
+        check_threshold(min_signers, max_signers)?;
 
-        if min_signers > max_signers {
+        let mut participants = BTreeMap::new();
+        let mut id_to_name = BTreeMap::new();
+
+        // Identifiers are 1-based u16 values, so check the count before any
+        // narrowing cast could wrap and reuse an identifier
+        if max_signers > u16::MAX as usize {
             bail!(
-                "min_signers ({}) cannot be greater than max_signers ({})",
-                min_signers,
-                max_signers
+                "too many participants ({}); at most {} are supported",
+                max_signers,
+                u16::MAX
             );
         }
 
-        if min_signers == 0 {
-            bail!("min_signers must be at least 1");
+        for (i, name) in participant_names.iter().enumerate() {
+            let index = u16::try_from(i + 1)?;
+            let id = Identifier::try_from(index)?;
+            participants.insert(name.as_ref().to_string(), id);
+            id_to_name.insert(id, name.as_ref().to_string());
+        }
+
+        check_charter_len(&charter, DEFAULT_MAX_CHARTER_LEN)?;
+
+        Ok(Self {
+            min_signers,
+            participants,
+            id_to_name,
+            charter,
+            max_charter_len: DEFAULT_MAX_CHARTER_LEN,
+            minimum_resolution: None,
+            reserved_slots: Vec::new(),
+            display_names: BTreeMap::new(),
+            weights: BTreeMap::new(),
+        })
+    }
+
+    /// Create a config with `total_slots` identifiers, assigning the first
+    /// ones to `participant_names` in order and reserving the rest for future
+    /// members (see `add_participant`)
+    pub fn reserve_identifier_gaps(
+        min_signers: usize,
+        participant_names: &[&'static str],
+        total_slots: usize,
+        charter: String,
+    ) -> Result<Self> {
+        let assigned = participant_names.len();
+        if total_slots < assigned || total_slots > u16::MAX as usize {
+            bail!(
+                "cannot reserve {} slots for {} participants",
+                total_slots,
+                assigned
+            );
+        }
+        let mut config =
+            Self::from_names(min_signers, participant_names, charter)?;
+        for slot in assigned + 1..=total_slots {
+            let id = Identifier::try_from(u16::try_from(slot)?)?;
+            config.reserved_slots.push(id);
+        }
+        Ok(config)
+    }
+
+    /// Assign the next reserved identifier to a new participant
+    /// Existing identifiers are untouched, but the group's key material no
+    /// longer matches the config until the new member's share exists (e.g.
+    /// from a DKG run over every slot)
+    pub fn add_participant(&mut self, name: &str) -> Result<Identifier> {
+        if self.participants.contains_key(name) {
+            bail!("duplicate participant name: {}", name);
+        }
+        if self.reserved_slots.is_empty() {
+            bail!("no reserved identifier left for {}", name);
+        }
+        let id = self.reserved_slots.remove(0);
+        self.participants.insert(name.to_string(), id);
+        self.id_to_name.insert(id, name.to_string());
+        Ok(id)
+    }
+
+    /// Get the identifiers still reserved for future participants
+    pub fn reserved_slots(&self) -> &[Identifier] { &self.reserved_slots }
+
+    /// Create a config from a text roster with one participant name per line
+    /// Names are trimmed; blank lines and lines starting with `#` are
+    /// skipped. Identifiers are assigned in line order, as with `new`.
+    pub fn from_lines(
+        reader: impl BufRead,
+        min_signers: usize,
+        charter: String,
+    ) -> Result<Self> {
+        let mut names: Vec<String> = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            let name = line.trim();
+            if name.is_empty() || name.starts_with('#') {
+                continue;
+            }
+            if names.iter().any(|n| n == name) {
+                bail!("duplicate participant name: {}", name);
+            }
+            names.push(name.to_string());
         }
+        Self::from_names(min_signers, &names, charter)
+    }
+
+    /// Create a config from a roster file (see `from_lines`)
+    pub fn from_participants_file(
+        path: impl AsRef<Path>,
+        min_signers: usize,
+        charter: String,
+    ) -> Result<Self> {
+        let file = File::open(path)?;
+        Self::from_lines(BufReader::new(file), min_signers, charter)
+    }
+
+    /// Create a config with explicitly pinned identifiers rather than
+    /// sequential ones, e.g. to match shares from an existing DKG
+    /// Identifiers must be nonzero and unique, as must names
+    pub fn with_identifiers(
+        min_signers: usize,
+        names_and_ids: &[(&'static str, u16)],
+        charter: String,
+    ) -> Result<Self> {
+        check_threshold(min_signers, names_and_ids.len())?;
 
         let mut participants = BTreeMap::new();
         let mut id_to_name = BTreeMap::new();
+        for &(name, raw_id) in names_and_ids {
+            if raw_id == 0 {
+                bail!("identifier for {} must be nonzero", name);
+            }
+            let id = Identifier::try_from(raw_id)?;
+            if participants.insert(name.to_string(), id).is_some() {
+                bail!("duplicate participant name: {}", name);
+            }
+            if let Some(other) = id_to_name.insert(id, name.to_string()) {
+                bail!(
+                    "identifier {} assigned to both {} and {}",
+                    raw_id,
+                    other,
+                    name
+                );
+            }
+        }
 
-        for (i, name) in participant_names.iter().enumerate() {
-            let id = Identifier::try_from((i + 1) as u16)?;
-            participants.insert((*name).to_string(), id);
-            id_to_name.insert(id, (*name).to_string());
+        check_charter_len(&charter, DEFAULT_MAX_CHARTER_LEN)?;
+
+        Ok(Self {
+            min_signers,
+            participants,
+            id_to_name,
+            charter,
+            max_charter_len: DEFAULT_MAX_CHARTER_LEN,
+            minimum_resolution: None,
+            reserved_slots: Vec::new(),
+            display_names: BTreeMap::new(),
+            weights: BTreeMap::new(),
+        })
+    }
+
+    /// Rebuild a config from its name-to-identifier mapping, e.g. after
+    /// decoding, revalidating the threshold, identifiers, and charter
+    pub(crate) fn from_participants(
+        min_signers: usize,
+        participants: BTreeMap<String, Identifier>,
+        charter: String,
+        max_charter_len: usize,
+    ) -> Result<Self> {
+        let max_signers = participants.len();
+        if min_signers == 0 || min_signers > max_signers {
+            bail!(
+                "invalid threshold {} for {} participants",
+                min_signers,
+                max_signers
+            );
+        }
+        let id_to_name: BTreeMap<Identifier, String> = participants
+            .iter()
+            .map(|(name, id)| (*id, name.clone()))
+            .collect();
+        if id_to_name.len() != max_signers {
+            bail!("duplicate participant identifiers");
         }
+        check_charter_len(&charter, max_charter_len)?;
+        Ok(Self {
+            min_signers,
+            participants,
+            id_to_name,
+            charter,
+            max_charter_len,
+            minimum_resolution: None,
+            reserved_slots: Vec::new(),
+            display_names: BTreeMap::new(),
+            weights: BTreeMap::new(),
+        })
+    }
+
+    /// Replace the charter length limit (default `DEFAULT_MAX_CHARTER_LEN`),
+    /// failing if the current charter already exceeds it
+    pub fn with_max_charter_len(
+        mut self,
+        max_charter_len: usize,
+    ) -> Result<Self> {
+        check_charter_len(&self.charter, max_charter_len)?;
+        self.max_charter_len = max_charter_len;
+        Ok(self)
+    }
 
-        Ok(Self { min_signers, participants, id_to_name, charter })
+    /// Forbid creating chains below `minimum` resolution
+    /// Lower resolutions have shorter links and so weaker security;
+    /// `FrostPmChain` genesis fails with `ResolutionBelowPolicy` below this
+    pub fn with_minimum_resolution(
+        mut self,
+        minimum: ProvenanceMarkResolution,
+    ) -> Self {
+        self.minimum_resolution = Some(minimum);
+        self
+    }
+
+    /// Give participant `name` a display name, e.g. "Dr. Alice Smith" for
+    /// "alice"
+    /// Display names appear only in human-facing output such as
+    /// `PublicGroupSummary`'s `Display`; signed messages and the roster hash
+    /// always use the protocol name
+    pub fn with_display_name(
+        mut self,
+        name: &str,
+        display_name: impl Into<String>,
+    ) -> Result<Self> {
+        if !self.participants.contains_key(name) {
+            bail!("unknown participant: {}", name);
+        }
+        self.display_names.insert(name.to_string(), display_name.into());
+        Ok(self)
+    }
+
+    /// Give participant `name` a quorum-planning weight (default 1)
+    /// Weights only guide `FrostGroup::participants_meeting_weight`; signing
+    /// still needs `min_signers` participants whatever their weights
+    pub fn with_participant_weight(
+        mut self,
+        name: &str,
+        weight: usize,
+    ) -> Result<Self> {
+        if !self.participants.contains_key(name) {
+            bail!("unknown participant: {}", name);
+        }
+        self.weights.insert(name.to_string(), weight);
+        Ok(self)
+    }
+
+    /// Create a new FROSTGroupConfig, validating every participant name
+    /// against `policy` first
+    pub fn new_with_policy(
+        min_signers: usize,
+        participant_names: &[&'static str],
+        charter: String,
+        policy: &impl NamePolicy,
+    ) -> Result<Self> {
+        for name in participant_names {
+            policy.validate(name)?;
+        }
+        Self::new(min_signers, participant_names, charter)
+    }
+
+    /// Create a 2-of-3 configuration; exactly three names are required
+    pub fn two_of_three(
+        participant_names: &[&'static str],
+        charter: String,
+    ) -> Result<Self> {
+        Self::fixed_topology(2, 3, participant_names, charter)
+    }
+
+    /// Create a 3-of-5 configuration; exactly five names are required
+    pub fn three_of_five(
+        participant_names: &[&'static str],
+        charter: String,
+    ) -> Result<Self> {
+        Self::fixed_topology(3, 5, participant_names, charter)
+    }
+
+    /// Create a simple-majority configuration with a threshold of
+    /// `floor(n/2) + 1` for `n` participant names
+    pub fn majority_of(
+        participant_names: &[&'static str],
+        charter: String,
+    ) -> Result<Self> {
+        if participant_names.is_empty() {
+            bail!("majority_of requires at least one participant");
+        }
+        Self::new(participant_names.len() / 2 + 1, participant_names, charter)
+    }
+
+    /// Create a config for a fixed m-of-n topology, checking the name count
+    fn fixed_topology(
+        min_signers: usize,
+        max_signers: usize,
+        participant_names: &[&'static str],
+        charter: String,
+    ) -> Result<Self> {
+        if participant_names.len() != max_signers {
+            bail!(
+                "{}-of-{} requires exactly {} participant names, got {}",
+                min_signers,
+                max_signers,
+                max_signers,
+                participant_names.len()
+            );
+        }
+        Self::new(min_signers, participant_names, charter)
     }
 
     /// Get the minimum number of signers required (threshold)
     pub fn min_signers(&self) -> usize { self.min_signers }
 
+    /// Whether any single participant can sign alone (a threshold of 1)
+    /// Allowed, but it gives up the protection of a threshold; groups report
+    /// it as `GroupWarning::SingleSigner`
+    pub fn is_single_signer(&self) -> bool { self.min_signers == 1 }
+
     /// Get the maximum number of participants
     pub fn max_signers(&self) -> usize { self.participants.len() }
 
@@ -65,6 +425,43 @@ impl FrostGroupConfig {
     /// Get the group's charter
     pub fn charter(&self) -> &str { &self.charter }
 
+    /// SHA-256 of the charter after whitespace normalization, for comparing
+    /// and binding charters in manifests
+    /// CRLF and lone CR line endings become LF, and trailing whitespace is
+    /// trimmed from every line and from the end, so editor-added whitespace
+    /// never changes the digest. The genesis message embeds the charter
+    /// verbatim, so chains bind the raw bytes instead.
+    pub fn charter_digest(&self) -> [u8; 32] {
+        let unified = self.charter.replace("\r\n", "\n").replace('\r', "\n");
+        let normalized = unified
+            .split('\n')
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n");
+        sha256(normalized.trim_end().as_bytes())
+    }
+
+    /// Get the maximum charter length in bytes
+    pub fn max_charter_len(&self) -> usize { self.max_charter_len }
+
+    /// Get the lowest resolution a chain may be created at, if limited
+    pub fn minimum_resolution(&self) -> Option<ProvenanceMarkResolution> {
+        self.minimum_resolution
+    }
+
+    /// Check `res` against the minimum resolution policy
+    pub fn check_resolution(
+        &self,
+        res: ProvenanceMarkResolution,
+    ) -> Result<()> {
+        match self.minimum_resolution {
+            Some(minimum) if res.link_length() < minimum.link_length() => {
+                bail!(FrostError::ResolutionBelowPolicy { minimum, got: res })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Get participant name by identifier
     pub fn participant_name(&self, id: &Identifier) -> &str {
         self.id_to_name
@@ -73,6 +470,20 @@ impl FrostGroupConfig {
             .unwrap_or("Unknown")
     }
 
+    /// Get the display name for participant `name`, falling back to the
+    /// protocol name when none was set
+    pub fn participant_display_name<'a>(&'a self, name: &'a str) -> &'a str {
+        self.display_names
+            .get(name)
+            .map(|s| s.as_str())
+            .unwrap_or(name)
+    }
+
+    /// Get the quorum-planning weight of participant `name` (default 1)
+    pub fn participant_weight(&self, name: &str) -> usize {
+        self.weights.get(name).copied().unwrap_or(1)
+    }
+
     /// Get participant names as a comma-separated string
     pub fn participant_names_string(&self) -> String {
         self.participants
@@ -82,8 +493,206 @@ impl FrostGroupConfig {
             .join(", ")
     }
 
+    /// Get participant names in identifier order (the order they were passed
+    /// to `new`, or ascending pinned identifier for `with_identifiers`),
+    /// rather than the lexicographic order of `participant_names_string`
+    pub fn participant_names_in_id_order(&self) -> Vec<String> {
+        self.id_to_name.values().cloned().collect()
+    }
+
+    /// Digest of the group's governance: threshold, charter, and every
+    /// participant's identifier and name, in identifier order
+    /// Each variable-length field is length-prefixed, so distinct rosters
+    /// never share an encoding; a field too large for its prefix is an error
+    pub fn roster_hash(&self) -> Result<[u8; 32]> {
+        let mut buf = b"PM:v1/roster".to_vec();
+        buf.extend_from_slice(&length_prefix(self.min_signers)?);
+        buf.extend_from_slice(&length_prefix(self.charter.len())?);
+        buf.extend_from_slice(self.charter.as_bytes());
+        for (id, name) in &self.id_to_name {
+            buf.extend_from_slice(&id.serialize());
+            buf.extend_from_slice(&length_prefix(name.len())?);
+            buf.extend_from_slice(name.as_bytes());
+        }
+        Ok(sha256(&buf))
+    }
+
+    /// Start editing a copy of this config's threshold, roster, or charter
+    /// A config never changes its threshold or roster in place; the builder
+    /// produces a new config that must be keyed afresh (see
+    /// `FrostGroup::reconfigure`)
+    pub fn reconfigure(self) -> FrostGroupConfigBuilder {
+        FrostGroupConfigBuilder {
+            min_signers: self.min_signers,
+            names: self.id_to_name.into_values().collect(),
+            charter: self.charter,
+            max_charter_len: self.max_charter_len,
+            minimum_resolution: self.minimum_resolution,
+            display_names: self.display_names,
+            weights: self.weights,
+        }
+    }
+
     /// Get a reference to the participants mapping (for internal use)
     pub(crate) fn participants(&self) -> &BTreeMap<String, Identifier> {
         &self.participants
     }
 }
+
+/// Editable parameters for a new group, from `FrostGroupConfig::reconfigure`
+/// `build` assigns sequential identifiers in roster order and drops reserved
+/// slots; the result is meant for a fresh trusted-dealer or DKG run
+#[derive(Debug, Clone)]
+pub struct FrostGroupConfigBuilder {
+    min_signers: usize,
+    names: Vec<String>,
+    charter: String,
+    max_charter_len: usize,
+    minimum_resolution: Option<ProvenanceMarkResolution>,
+    display_names: BTreeMap<String, String>,
+    weights: BTreeMap<String, usize>,
+}
+
+impl FrostGroupConfigBuilder {
+    /// Replace the threshold
+    pub fn min_signers(mut self, min_signers: usize) -> Self {
+        self.min_signers = min_signers;
+        self
+    }
+
+    /// Append a participant after the existing ones
+    pub fn add_participant(mut self, name: impl Into<String>) -> Self {
+        self.names.push(name.into());
+        self
+    }
+
+    /// Drop participant `name`, along with its display name and weight
+    pub fn remove_participant(mut self, name: &str) -> Self {
+        self.names.retain(|n| n != name);
+        self.display_names.remove(name);
+        self.weights.remove(name);
+        self
+    }
+
+    /// Replace the charter
+    pub fn charter(mut self, charter: String) -> Self {
+        self.charter = charter;
+        self
+    }
+
+    /// Validate the parameters and produce the new config
+    pub fn build(self) -> Result<FrostGroupConfig> {
+        for (i, name) in self.names.iter().enumerate() {
+            if self.names[..i].contains(name) {
+                bail!("duplicate participant name: {}", name);
+            }
+        }
+        let mut config = FrostGroupConfig::from_names(
+            self.min_signers,
+            &self.names,
+            self.charter,
+        )?
+        .with_max_charter_len(self.max_charter_len)?;
+        config.minimum_resolution = self.minimum_resolution;
+        for (name, display_name) in self.display_names {
+            config = config.with_display_name(&name, display_name)?;
+        }
+        for (name, weight) in self.weights {
+            config = config.with_participant_weight(&name, weight)?;
+        }
+        Ok(config)
+    }
+}
+
+/// Check that a threshold is satisfiable by `max_signers` participants
+fn check_threshold(min_signers: usize, max_signers: usize) -> Result<()> {
+    if min_signers > max_signers {
+        bail!(
+            "min_signers ({}) cannot be greater than max_signers ({})",
+            min_signers,
+            max_signers
+        );
+    }
+
+    if min_signers == 0 {
+        bail!("min_signers must be at least 1");
+    }
+    Ok(())
+}
+
+/// Reject a charter longer than `max` bytes
+fn check_charter_len(charter: &str, max: usize) -> Result<()> {
+    if charter.len() > max {
+        bail!(FrostError::CharterTooLong { max, got: charter.len() });
+    }
+    Ok(())
+}
+
+/// Serialized form of `FrostGroupConfig`; the reverse mapping is rebuilt and
+/// the whole config revalidated on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct FrostGroupConfigWire {
+    min_signers: usize,
+    participants: BTreeMap<String, Identifier>,
+    charter: String,
+    #[serde(default = "default_max_charter_len")]
+    max_charter_len: usize,
+    #[serde(default)]
+    minimum_resolution: Option<ProvenanceMarkResolution>,
+    #[serde(default)]
+    reserved_slots: Vec<Identifier>,
+    #[serde(default)]
+    display_names: BTreeMap<String, String>,
+    #[serde(default)]
+    weights: BTreeMap<String, usize>,
+}
+
+#[cfg(feature = "serde")]
+fn default_max_charter_len() -> usize { DEFAULT_MAX_CHARTER_LEN }
+
+#[cfg(feature = "serde")]
+impl From<FrostGroupConfig> for FrostGroupConfigWire {
+    fn from(config: FrostGroupConfig) -> Self {
+        Self {
+            min_signers: config.min_signers,
+            participants: config.participants,
+            charter: config.charter,
+            max_charter_len: config.max_charter_len,
+            minimum_resolution: config.minimum_resolution,
+            reserved_slots: config.reserved_slots,
+            display_names: config.display_names,
+            weights: config.weights,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<FrostGroupConfigWire> for FrostGroupConfig {
+    type Error = anyhow::Error;
+
+    fn try_from(wire: FrostGroupConfigWire) -> Result<Self> {
+        let mut config = Self::from_participants(
+            wire.min_signers,
+            wire.participants,
+            wire.charter,
+            wire.max_charter_len,
+        )?;
+        config.minimum_resolution = wire.minimum_resolution;
+        if wire
+            .reserved_slots
+            .iter()
+            .any(|id| config.id_to_name.contains_key(id))
+        {
+            bail!("reserved identifier is already assigned");
+        }
+        config.reserved_slots = wire.reserved_slots;
+        for (name, display_name) in wire.display_names {
+            config = config.with_display_name(&name, display_name)?;
+        }
+        for (name, weight) in wire.weights {
+            config = config.with_participant_weight(&name, weight)?;
+        }
+        Ok(config)
+    }
+}
@@ -0,0 +1,31 @@
+//! Failure injection for fuzzing, compiled only with `--cfg fuzzing` (as
+//! `cargo fuzz` builds the targets in `fuzz/`)
+//!
+//! Commitments and messages reach a ceremony as caller input already; this
+//! lets a fuzzer also replace the signature shares a co-located ceremony
+//! produces, so aggregation sees arbitrary bytes.
+
+use std::cell::RefCell;
+
+use anyhow::Result;
+use frost_ed25519::round2::SignatureShare;
+
+thread_local! {
+    static INJECTED_SHARE: RefCell<Option<Vec<u8>>> = const {
+        RefCell::new(None)
+    };
+}
+
+/// Replace every signature share produced on this thread with `bytes`
+/// until cleared with `None`
+pub fn inject_share(bytes: Option<Vec<u8>>) {
+    INJECTED_SHARE.with(|injected| *injected.borrow_mut() = bytes);
+}
+
+/// Apply the injected share, if any; malformed bytes are an error
+pub(crate) fn tamper_share(share: SignatureShare) -> Result<SignatureShare> {
+    INJECTED_SHARE.with(|injected| match &*injected.borrow() {
+        Some(bytes) => Ok(SignatureShare::deserialize(bytes)?),
+        None => Ok(share),
+    })
+}
@@ -7,18 +7,54 @@ with a focus on usability and abstraction. It includes:
 - `FROSTGroupConfig` - Configuration for FROST groups with human-readable participant names
 - `FROSTGroup` - A fully constituted FROST group with all key material for signing
 - FROST-controlled Provenance Mark chain functionality for distributed attestation
+- `ShareEnvelope` - Checksummed per-participant share export for backups
+- `PublicGroupSummary` - Public-only group material for auditors
 
 The library abstracts away the complexity of key generation methods (trusted dealer vs DKG)
 and provides a clean, high-level API for threshold signature operations and provenance mark chains.
+
+The library never sources randomness itself: key generation and every Round-1 commitment take a
+caller-provided `RngCore + CryptoRng`, so a seeded RNG makes an entire chain reproducible.
+
+Most programs need only `use frost_pm_test::prelude::*;`.
 */
 
+pub mod archive;
+pub mod ceremony;
+pub mod demo;
+pub mod error;
 pub mod frost_group;
 pub mod frost_group_config;
+#[cfg(fuzzing)]
+pub mod fuzzing;
+pub mod manifest;
+pub mod message;
+pub mod observer;
 pub mod pm_chain;
+pub mod prelude;
+pub mod public_summary;
+pub mod receipt;
+pub mod share_envelope;
+pub mod shared_chain;
+pub mod validation;
 
-/// Re-export rand_core from frost_ed25519 for callers needing compatible
-/// RNG types
+pub use archive::{CommitmentAck, GenesisProof, MarkRecord};
+pub use ceremony::{CancellationToken, CeremonyState};
+pub use error::FrostError;
+/// The `rand_core` version `frost_ed25519` is built against
+/// RNGs passed to key generation and Round-1 commitments must implement
+/// these traits, so use this re-export (or `prelude`) rather than a
+/// separately versioned `rand_core`, whose traits would not match
 pub use frost_ed25519::rand_core;
 pub use frost_group::FrostGroup;
-pub use frost_group_config::FrostGroupConfig;
+pub use frost_group_config::{
+    FrostGroupConfig, FrostGroupConfigBuilder, NamePolicy, ReservedNames,
+};
+pub use manifest::ChainManifest;
+pub use observer::GroupWarning;
 pub use pm_chain::FrostPmChain;
+pub use public_summary::{GroupAttestation, PublicGroupSummary};
+pub use receipt::PrecommitReceipt;
+pub use share_envelope::ShareEnvelope;
+pub use shared_chain::SharedChain;
+pub use validation::{ProposalCheck, ValidationReport};
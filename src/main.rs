@@ -12,8 +12,7 @@ The demo creates a 2-of-3 FROST group and demonstrates:
 4. Indistinguishability from single-signer chains
 */
 
-mod demo;
-
 use anyhow::Result;
+use frost_pm_test::demo;
 
 fn main() -> Result<()> { demo::run_demo() }
@@ -0,0 +1,131 @@
+use anyhow::{Result, bail};
+use chrono::DateTime;
+use dcbor::{CBOR, Date};
+use provenance_mark::ProvenanceMarkResolution;
+
+use crate::{FrostGroup, PublicGroupSummary};
+
+/// Compact header describing a chain for auditors
+///
+/// Produced by `FrostPmChain::export_chain_manifest`. Unlike a full mark
+/// archive it only identifies the chain, its controlling group, and its
+/// current tip. Encodes to CBOR as a seven-element array, with the date as
+/// exact (seconds, nanoseconds) integers rather than a lossy float.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainManifest {
+    chain_id: Vec<u8>,
+    res: ProvenanceMarkResolution,
+    group_fingerprint: [u8; 32],
+    seq: u32,
+    date: Date,
+    charter_hash: [u8; 32],
+}
+
+impl ChainManifest {
+    pub(crate) fn new(
+        chain_id: Vec<u8>,
+        res: ProvenanceMarkResolution,
+        group_fingerprint: [u8; 32],
+        seq: u32,
+        date: Date,
+        charter_hash: [u8; 32],
+    ) -> Self {
+        Self { chain_id, res, group_fingerprint, seq, date, charter_hash }
+    }
+
+    /// Get the chain id (the genesis mark's key)
+    pub fn chain_id(&self) -> &[u8] { &self.chain_id }
+
+    /// Get the chain's resolution
+    pub fn res(&self) -> ProvenanceMarkResolution { self.res }
+
+    /// Get the fingerprint of the controlling group (see
+    /// `FrostGroup::fingerprint`)
+    pub fn group_fingerprint(&self) -> [u8; 32] { self.group_fingerprint }
+
+    /// Get the seq of the chain's latest mark
+    pub fn seq(&self) -> u32 { self.seq }
+
+    /// Get the date of the chain's latest mark
+    pub fn date(&self) -> Date { self.date }
+
+    /// Get the group's whitespace-normalized charter digest (see
+    /// `FrostGroupConfig::charter_digest`)
+    pub fn charter_hash(&self) -> [u8; 32] { self.charter_hash }
+
+    /// Check that `summary` describes the group controlling this chain
+    /// Compares the group fingerprint and the charter digest, so a verifier
+    /// holding a manifest and a separately obtained summary can't pair the
+    /// right chain with the wrong group
+    pub fn verify_group(&self, summary: &PublicGroupSummary) -> Result<()> {
+        if self.group_fingerprint != summary.fingerprint() {
+            bail!("manifest group fingerprint does not match the summary");
+        }
+        if self.charter_hash != summary.config().charter_digest() {
+            bail!("manifest charter digest does not match the summary");
+        }
+        Ok(())
+    }
+
+    /// Decode a manifest from its CBOR encoding
+    pub fn from_cbor_data(data: &[u8]) -> Result<Self> {
+        Ok(Self::try_from(CBOR::try_from_data(data)?)?)
+    }
+}
+
+impl From<ChainManifest> for CBOR {
+    fn from(manifest: ChainManifest) -> Self {
+        vec![
+            CBOR::to_byte_string(manifest.chain_id),
+            manifest.res.into(),
+            CBOR::to_byte_string(manifest.group_fingerprint),
+            manifest.seq.into(),
+            manifest.date.datetime().timestamp().into(),
+            manifest.date.datetime().timestamp_subsec_nanos().into(),
+            CBOR::to_byte_string(manifest.charter_hash),
+        ]
+        .into()
+    }
+}
+
+impl TryFrom<CBOR> for ChainManifest {
+    type Error = dcbor::Error;
+
+    fn try_from(cbor: CBOR) -> dcbor::Result<Self> {
+        let v = CBOR::try_into_array(cbor)?;
+        let [
+            chain_id,
+            res,
+            group_fingerprint,
+            seq,
+            secs,
+            nanos,
+            charter_hash,
+        ] = <[CBOR; 7]>::try_from(v)
+            .map_err(|_| dcbor::Error::from("invalid manifest length"))?;
+        let digest = |cbor: CBOR| -> dcbor::Result<[u8; 32]> {
+            <[u8; 32]>::try_from(CBOR::try_into_byte_string(cbor)?)
+                .map_err(|_| "invalid manifest digest length".into())
+        };
+        Ok(Self {
+            chain_id: CBOR::try_into_byte_string(chain_id)?,
+            res: res.try_into()?,
+            group_fingerprint: digest(group_fingerprint)?,
+            seq: seq.try_into()?,
+            date: DateTime::from_timestamp(secs.try_into()?, nanos.try_into()?)
+                .map(Date::from_datetime)
+                .ok_or("invalid manifest date")?,
+            charter_hash: digest(charter_hash)?,
+        })
+    }
+}
+
+/// Check that a manifest describes a chain controlled by `group`
+/// Delegates to `ChainManifest::verify_group` on the group's public summary;
+/// the chain id and tip are checked against marks, not against the group
+pub fn verify_manifest_against_group(
+    manifest: &ChainManifest,
+    group: &FrostGroup,
+) -> Result<()> {
+    manifest.verify_group(&group.public_summary())
+}
@@ -0,0 +1,567 @@
+use std::{io::Read, path::Path};
+
+use anyhow::{Result, anyhow, bail};
+use bc_crypto::sha256;
+use chrono::{DateTime, Utc};
+use dcbor::{CBOR, CBOREncodable, Date, Map};
+use frost_ed25519::rand_core::{CryptoRng, RngCore};
+use provenance_mark::ProvenanceMarkResolution;
+use sha2::{Digest, Sha256};
+
+use crate::PublicGroupSummary;
+
+/// Compute the digest that binds a mark's info into signed messages
+/// SHA-256 of the info's canonical (deterministic) CBOR encoding, or of the
+/// empty string when there is no info, so messages stay fixed-size however
+/// large the info is
+pub fn obj_hash(info: Option<&impl CBOREncodable>) -> [u8; 32] {
+    match info {
+        Some(info) => sha256(info.to_cbor_data()),
+        None => sha256([]),
+    }
+}
+
+/// Encode `len` as the 4-byte big-endian prefix used in signed encodings
+/// Fails rather than truncating a length that does not fit in a `u32`, which
+/// would silently produce an ambiguous encoding
+pub fn length_prefix(len: usize) -> Result<[u8; 4]> {
+    let len = u32::try_from(len)
+        .map_err(|_| anyhow!("length {} overflows a u32 prefix", len))?;
+    Ok(len.to_be_bytes())
+}
+
+/// Size of the chunks `prehash` reads at a time
+const PREHASH_CHUNK_LEN: usize = 64 * 1024;
+
+/// SHA-256 a large payload in fixed-size chunks, without holding it in memory
+/// Sign the result with `FrostGroup::round_2_sign_prehashed`, which signs the
+/// digest under `MessagePurpose::Prehashed`
+pub fn prehash(mut reader: impl Read) -> Result<[u8; 32]> {
+    let mut hasher = Sha256::new();
+    let mut chunk = vec![0u8; PREHASH_CHUNK_LEN];
+    loop {
+        let n = reader.read(&mut chunk)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&chunk[..n]);
+    }
+    Ok(hasher.finalize().into())
+}
+
+/// The message actually signed for `message` with associated data `aad`
+/// The length-prefixed AAD precedes the message, and the result is signed
+/// under `MessagePurpose::Aad`, so the AAD is bound to the signature (for
+/// audit context such as a request id) while staying out of any mark. Fails
+/// only if `aad` overflows the length prefix
+pub fn aad_message(aad: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+    let mut buf = length_prefix(aad.len())?.to_vec();
+    buf.extend_from_slice(aad);
+    buf.extend_from_slice(message);
+    Ok(buf)
+}
+
+/// What a message signed by a group is for
+/// Every message signed through `FrostGroup` is prefixed with its purpose's
+/// tag byte (see `framed_message`), so a signature made for one purpose
+/// never verifies as another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessagePurpose {
+    /// A chain's genesis message (`FrostPmChain::message_0`)
+    Genesis,
+    /// A chain's append message (`FrostPmChain::message_next`)
+    Mark,
+    /// Any other message; the purpose of `FrostGroup::round_2_sign`
+    Application,
+    /// A group's statement of its own roster (`FrostGroup::self_attest`)
+    Attestation,
+    /// A payload digest from `prehash`
+    /// (`FrostGroup::round_2_sign_prehashed`)
+    Prehashed,
+    /// An application message with associated data, as an `aad_message`
+    /// (`FrostGroup::round_2_sign_with_aad`)
+    Aad,
+}
+
+impl MessagePurpose {
+    /// The byte prepended to messages signed for this purpose
+    pub fn tag(self) -> u8 {
+        match self {
+            MessagePurpose::Genesis => 0x01,
+            MessagePurpose::Mark => 0x02,
+            MessagePurpose::Application => 0x03,
+            MessagePurpose::Attestation => 0x04,
+            MessagePurpose::Prehashed => 0x05,
+            MessagePurpose::Aad => 0x06,
+        }
+    }
+
+    /// The purpose whose tag byte is `tag`, if any
+    pub fn from_tag(tag: u8) -> Option<Self> {
+        [
+            MessagePurpose::Genesis,
+            MessagePurpose::Mark,
+            MessagePurpose::Application,
+            MessagePurpose::Attestation,
+            MessagePurpose::Prehashed,
+            MessagePurpose::Aad,
+        ]
+        .into_iter()
+        .find(|purpose| purpose.tag() == tag)
+    }
+}
+
+/// The bytes actually signed for `message` under `purpose`: the purpose's
+/// tag byte followed by the message
+pub fn framed_message(purpose: MessagePurpose, message: &[u8]) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(message.len() + 1);
+    framed.push(purpose.tag());
+    framed.extend_from_slice(message);
+    framed
+}
+
+/// Encode a date for inclusion in signed messages
+/// Pinned to whole seconds since the Unix epoch as a canonical CBOR integer,
+/// so signed bytes don't depend on dcbor's default `Date` encoding or on
+/// sub-second precision that marks may not preserve
+pub fn encode_date(date: &Date) -> Vec<u8> {
+    CBOR::from(normalize_date(date).datetime().timestamp()).to_cbor_data()
+}
+
+/// Normalize a date to UTC before it is compared or encoded
+/// `Date` currently stores UTC, so this pins that explicitly: monotonicity
+/// checks and signed bytes stay timezone-independent however a date was
+/// constructed (e.g. parsed from a string with an offset)
+pub fn normalize_date(date: &Date) -> Date {
+    Date::from_datetime(date.datetime().with_timezone(&Utc))
+}
+
+/// Application info bound into a mark
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkInfo {
+    /// Info embedded directly in the mark
+    Inline(CBOR),
+    /// A large off-chain object, authenticated by its SHA-256 digest
+    /// Encoded as the map `{"sha256": h'…', "uri": "…"}` (no `uri` key when
+    /// absent), so the digest is bound into the mark while it stays small
+    Reference { hash: [u8; 32], uri: Option<String> },
+    /// A record revoking the earlier mark `target_seq`
+    /// Encoded as the map `{"revokes": target_seq, "reason": "…"}` (no
+    /// `reason` key when absent)
+    Revocation { target_seq: u32, reason: Option<String> },
+    /// A checkpoint embedding the group's full public summary, from which a
+    /// verifier can audit later marks (see `FrostPmChain::append_checkpoint`)
+    /// Encoded as the map `{"checkpoint": summary}`
+    Checkpoint(Box<PublicGroupSummary>),
+    /// A content-free liveness mark proving the group still controls the
+    /// chain (see `FrostPmChain::append_heartbeat`); the random nonce makes
+    /// every heartbeat distinct
+    /// Encoded as the map `{"heartbeat": h'…'}`
+    Heartbeat { nonce: [u8; 16] },
+}
+
+impl MarkInfo {
+    /// Reference `content` by its SHA-256 digest and an optional location
+    pub fn reference(content: &[u8], uri: Option<String>) -> Self {
+        MarkInfo::Reference { hash: sha256(content), uri }
+    }
+
+    /// Reference a file by the SHA-256 of its contents, recording its
+    /// absolute path as a `file://` URI
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = std::fs::canonicalize(path)?;
+        let content = std::fs::read(&path)?;
+        let uri = format!("file://{}", path.display());
+        Ok(Self::reference(&content, Some(uri)))
+    }
+
+    /// Heartbeat info with a fresh random nonce
+    pub fn heartbeat(rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        let mut nonce = [0u8; 16];
+        rng.fill_bytes(&mut nonce);
+        MarkInfo::Heartbeat { nonce }
+    }
+
+    /// Whether `content` is the object this info references
+    /// Always false for inline info, revocations, checkpoints, and heartbeats
+    pub fn references(&self, content: &[u8]) -> bool {
+        match self {
+            MarkInfo::Reference { hash, .. } => *hash == sha256(content),
+            MarkInfo::Inline(_)
+            | MarkInfo::Revocation { .. }
+            | MarkInfo::Checkpoint(_)
+            | MarkInfo::Heartbeat { .. } => false,
+        }
+    }
+}
+
+impl From<MarkInfo> for CBOR {
+    fn from(info: MarkInfo) -> Self {
+        match info {
+            MarkInfo::Inline(cbor) => cbor,
+            MarkInfo::Reference { hash, uri } => {
+                let mut map = Map::new();
+                map.insert("sha256", CBOR::to_byte_string(hash));
+                if let Some(uri) = uri {
+                    map.insert("uri", uri);
+                }
+                map.into()
+            }
+            MarkInfo::Revocation { target_seq, reason } => {
+                let mut map = Map::new();
+                map.insert("revokes", target_seq);
+                if let Some(reason) = reason {
+                    map.insert("reason", reason);
+                }
+                map.into()
+            }
+            MarkInfo::Checkpoint(summary) => {
+                let mut map = Map::new();
+                map.insert("checkpoint", CBOR::from(&*summary));
+                map.into()
+            }
+            MarkInfo::Heartbeat { nonce } => {
+                let mut map = Map::new();
+                map.insert("heartbeat", CBOR::to_byte_string(nonce));
+                map.into()
+            }
+        }
+    }
+}
+
+impl From<CBOR> for MarkInfo {
+    /// Decode info read back from a mark; anything not shaped exactly like a
+    /// reference, a revocation, a checkpoint, or a heartbeat is inline info
+    fn from(cbor: CBOR) -> Self {
+        let decoded = cbor.clone().try_into_map().ok().and_then(|map| {
+            decode_reference(&map)
+                .or_else(|| decode_revocation(&map))
+                .or_else(|| decode_checkpoint(&map))
+                .or_else(|| decode_heartbeat(&map))
+        });
+        decoded.unwrap_or(MarkInfo::Inline(cbor))
+    }
+}
+
+/// Decode the map `{"sha256": h'…', "uri": "…"}`, with no other keys
+fn decode_reference(map: &Map) -> Option<MarkInfo> {
+    let hash = map
+        .get::<_, CBOR>("sha256")
+        .and_then(|hash| hash.try_into_byte_string().ok())
+        .and_then(|hash| <[u8; 32]>::try_from(hash).ok())?;
+    let uri = optional_text(map, "uri")?;
+    let keys = 1 + uri.is_some() as usize;
+    (map.len() == keys).then_some(MarkInfo::Reference { hash, uri })
+}
+
+/// Decode the map `{"revokes": seq, "reason": "…"}`, with no other keys
+fn decode_revocation(map: &Map) -> Option<MarkInfo> {
+    let target_seq = map
+        .get::<_, CBOR>("revokes")
+        .and_then(|seq| u32::try_from(seq).ok())?;
+    let reason = optional_text(map, "reason")?;
+    let keys = 1 + reason.is_some() as usize;
+    (map.len() == keys).then_some(MarkInfo::Revocation { target_seq, reason })
+}
+
+/// Decode the map `{"checkpoint": summary}`, with no other keys
+fn decode_checkpoint(map: &Map) -> Option<MarkInfo> {
+    let summary = map.get::<_, CBOR>("checkpoint")?;
+    let summary = PublicGroupSummary::try_from(summary).ok()?;
+    (map.len() == 1).then(|| MarkInfo::Checkpoint(Box::new(summary)))
+}
+
+/// Decode the map `{"heartbeat": h'…'}` with a 16-byte nonce, with no other
+/// keys
+fn decode_heartbeat(map: &Map) -> Option<MarkInfo> {
+    let nonce = map
+        .get::<_, CBOR>("heartbeat")
+        .and_then(|nonce| nonce.try_into_byte_string().ok())
+        .and_then(|nonce| <[u8; 16]>::try_from(nonce).ok())?;
+    (map.len() == 1).then_some(MarkInfo::Heartbeat { nonce })
+}
+
+/// Read an optional text value: `Some(None)` when the key is absent, `None`
+/// when it is present but not text
+fn optional_text(map: &Map, key: &str) -> Option<Option<String>> {
+    match map.get::<_, CBOR>(key) {
+        Some(value) => Some(Some(value.try_into_text().ok()?)),
+        None => Some(None),
+    }
+}
+
+/// The fields of a chain message, as built by `FrostPmChain::message_0` and
+/// its predecessor variants (genesis) or `FrostPmChain::message_next` (mark)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainMessage {
+    /// The chain's resolution
+    pub res: ProvenanceMarkResolution,
+    /// The group's threshold
+    pub min_signers: usize,
+    /// The number of participants
+    pub max_signers: usize,
+    /// Participants' protocol names, in signed order
+    pub participants: Vec<String>,
+    /// The group's charter
+    pub charter: String,
+    /// The mark's sequence number; `None` for a genesis message
+    pub seq: Option<u32>,
+    /// The mark's date, to the second (see `encode_date`)
+    pub date: Date,
+    /// `obj_hash` of the mark's info
+    pub info_hash: [u8; 32],
+    /// Serialized predecessor verifying key bound into a genesis message
+    pub predecessor_key: Option<Vec<u8>>,
+    /// Hash of a prior chain's last mark bound into a genesis message
+    pub predecessor_chain: Option<Vec<u8>>,
+}
+
+/// A message signed by a group, recovered by `parse_signed_message`
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignedMessage {
+    /// A chain's genesis message
+    Genesis(ChainMessage),
+    /// A chain's append message
+    Mark(ChainMessage),
+    /// A signature over a payload digest (`MessagePurpose::Prehashed`)
+    Prehashed([u8; 32]),
+    /// An application message with associated data
+    /// (`MessagePurpose::Aad`)
+    WithAad { aad: Vec<u8>, message: Vec<u8> },
+    /// An application message (`MessagePurpose::Application`), returned
+    /// verbatim whatever its contents
+    Application(Vec<u8>),
+    /// A group's attestation of its own public summary
+    Attestation(Vec<u8>),
+}
+
+/// Parse the bytes a group actually signs (see `framed_message`), reading
+/// the purpose tag to decide how to parse the rest
+/// Dispatch depends on the tag alone, never on the message's contents, so
+/// an application message cannot pose as a digest or an AAD message
+pub fn parse_signed_message(framed: &[u8]) -> Result<SignedMessage> {
+    let (&tag, message) = framed
+        .split_first()
+        .ok_or_else(|| anyhow!("signed message is empty"))?;
+    let purpose = MessagePurpose::from_tag(tag)
+        .ok_or_else(|| anyhow!("unknown message purpose tag {:#04x}", tag))?;
+    Ok(match purpose {
+        MessagePurpose::Genesis => {
+            SignedMessage::Genesis(parse_genesis_message(utf8(message)?)?)
+        }
+        MessagePurpose::Mark => {
+            SignedMessage::Mark(parse_mark_message(utf8(message)?)?)
+        }
+        MessagePurpose::Application => {
+            SignedMessage::Application(message.to_vec())
+        }
+        MessagePurpose::Attestation => {
+            SignedMessage::Attestation(message.to_vec())
+        }
+        MessagePurpose::Prehashed => {
+            SignedMessage::Prehashed(parse_hash_message(message)?)
+        }
+        MessagePurpose::Aad => {
+            let (aad, message) = parse_aad_message(message)?;
+            SignedMessage::WithAad {
+                aad: aad.to_vec(),
+                message: message.to_vec(),
+            }
+        }
+    })
+}
+
+/// Parse a genesis message, rejecting anything `FrostPmChain` would not
+/// have built
+pub fn parse_genesis_message(message: &str) -> Result<ChainMessage> {
+    parse_chain_message(message, true)
+}
+
+/// Parse a mark (append) message, rejecting anything `FrostPmChain` would
+/// not have built
+pub fn parse_mark_message(message: &str) -> Result<ChainMessage> {
+    parse_chain_message(message, false)
+}
+
+/// Recover the digest signed under `MessagePurpose::Prehashed`
+pub fn parse_hash_message(message: &[u8]) -> Result<[u8; 32]> {
+    <[u8; 32]>::try_from(message).map_err(|_| {
+        anyhow!("prehashed message digest is {} bytes", message.len())
+    })
+}
+
+/// Split an `aad_message` into its associated data and message
+pub fn parse_aad_message(message: &[u8]) -> Result<(&[u8], &[u8])> {
+    let (prefix, rest) = message
+        .split_first_chunk::<4>()
+        .ok_or_else(|| anyhow!("AAD message is missing its length prefix"))?;
+    let len = u32::from_be_bytes(*prefix) as usize;
+    if len > rest.len() {
+        bail!("AAD length {} exceeds the {} bytes present", len, rest.len());
+    }
+    Ok(rest.split_at(len))
+}
+
+/// View a chain message's bytes as text
+fn utf8(message: &[u8]) -> Result<&str> {
+    std::str::from_utf8(message)
+        .map_err(|_| anyhow!("chain message is not UTF-8"))
+}
+
+/// Parse the shared layout of genesis and mark messages
+fn parse_chain_message(message: &str, genesis: bool) -> Result<ChainMessage> {
+    let mut lines = Lines(Some(message));
+    lines.exact("FROST Provenance Mark Chain")?;
+    let (res, threshold) = lines
+        .field("Resolution: ")?
+        .split_once(", Threshold: ")
+        .ok_or_else(|| anyhow!("malformed chain message threshold"))?;
+    let res = [
+        ProvenanceMarkResolution::Low,
+        ProvenanceMarkResolution::Medium,
+        ProvenanceMarkResolution::Quartile,
+        ProvenanceMarkResolution::High,
+    ]
+    .into_iter()
+    .find(|candidate| candidate.to_string() == res)
+    .ok_or_else(|| anyhow!("unknown resolution: {}", res))?;
+    let (min_signers, max_signers) = threshold
+        .split_once(" of ")
+        .ok_or_else(|| anyhow!("malformed chain message threshold"))?;
+    let (min_signers, max_signers) =
+        (number(min_signers)?, number(max_signers)?);
+    let participants: Vec<String> = lines
+        .field("Participants: ")?
+        .split(", ")
+        .map(str::to_string)
+        .collect();
+    if participants.len() != max_signers {
+        bail!(
+            "chain message lists {} participants for {} signers",
+            participants.len(),
+            max_signers
+        );
+    }
+    let charter = lines.charter()?;
+    let seq = if genesis {
+        None
+    } else {
+        Some(number(lines.field("Sequence: ")?)?)
+    };
+    let date = parse_date(lines.field("Date: ")?)?;
+    let info_hash = hex::decode(lines.field("Info Hash: ")?)?;
+    let info_hash = <[u8; 32]>::try_from(info_hash)
+        .map_err(|_| anyhow!("info hash is not 32 bytes"))?;
+    let mut predecessor_key = None;
+    let mut predecessor_chain = None;
+    if genesis {
+        if let Some(key) = lines.optional_field("Predecessor Key: ")? {
+            predecessor_key = Some(hex::decode(key)?);
+        }
+        if let Some(hash) = lines.optional_field("Predecessor Chain: ")? {
+            predecessor_chain = Some(hex::decode(hash)?);
+        }
+    }
+    if lines.0.is_some() {
+        bail!("chain message has trailing content");
+    }
+    Ok(ChainMessage {
+        res,
+        min_signers,
+        max_signers,
+        participants,
+        charter,
+        seq,
+        date,
+        info_hash,
+        predecessor_key,
+        predecessor_chain,
+    })
+}
+
+/// Parse a decimal number, rejecting forms `format!` never produces (such
+/// as leading zeros or a `+` sign)
+fn number<T: std::str::FromStr + ToString>(text: &str) -> Result<T> {
+    text.parse::<T>()
+        .ok()
+        .filter(|n| n.to_string() == text)
+        .ok_or_else(|| anyhow!("malformed number in chain message: {}", text))
+}
+
+/// Decode a hex `encode_date` field, rejecting non-canonical encodings
+fn parse_date(hex_date: &str) -> Result<Date> {
+    let bytes = hex::decode(hex_date)?;
+    let seconds = i64::try_from(CBOR::try_from_data(&bytes)?)?;
+    let date = DateTime::from_timestamp(seconds, 0)
+        .map(Date::from_datetime)
+        .ok_or_else(|| anyhow!("chain message date out of range"))?;
+    if encode_date(&date) != bytes {
+        bail!("chain message date is not canonically encoded");
+    }
+    Ok(date)
+}
+
+/// The unread remainder of a chain message; `None` once the final line
+/// (which has no trailing newline) has been read
+struct Lines<'a>(Option<&'a str>);
+
+impl<'a> Lines<'a> {
+    /// Read the next line
+    fn next(&mut self) -> Result<&'a str> {
+        let rest = self.0.ok_or_else(|| anyhow!("chain message ends early"))?;
+        let (line, rest) = match rest.split_once('\n') {
+            Some((line, rest)) => (line, Some(rest)),
+            None => (rest, None),
+        };
+        self.0 = rest;
+        Ok(line)
+    }
+
+    /// Read a line that must be exactly `expected`
+    fn exact(&mut self, expected: &str) -> Result<()> {
+        if self.next()? != expected {
+            bail!("chain message does not start with \"{}\"", expected);
+        }
+        Ok(())
+    }
+
+    /// Read a line starting with `label`, returning the rest of it
+    fn field(&mut self, label: &str) -> Result<&'a str> {
+        let line = self.next()?;
+        line.strip_prefix(label).ok_or_else(|| {
+            anyhow!("expected \"{}\" in chain message", label.trim_end())
+        })
+    }
+
+    /// Read a line starting with `label` if the next line has it
+    fn optional_field(&mut self, label: &str) -> Result<Option<&'a str>> {
+        match self.0 {
+            Some(rest) if rest.starts_with(label) => {
+                self.field(label).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Read the length-prefixed `Charter: <len>:<charter>` line; the charter
+    /// may itself contain newlines
+    fn charter(&mut self) -> Result<String> {
+        let rest = self
+            .0
+            .and_then(|rest| rest.strip_prefix("Charter: "))
+            .ok_or_else(|| anyhow!("expected \"Charter:\" in chain message"))?;
+        let (len, rest) = rest
+            .split_once(':')
+            .ok_or_else(|| anyhow!("charter is missing its length prefix"))?;
+        let len: usize = number(len)?;
+        let (Some(charter), Some(rest)) = (rest.get(..len), rest.get(len..))
+        else {
+            bail!("charter is shorter than its length prefix");
+        };
+        let rest = rest.strip_prefix('\n').ok_or_else(|| {
+            anyhow!("charter does not end where its length prefix says")
+        })?;
+        self.0 = Some(rest);
+        Ok(charter.to_string())
+    }
+}
@@ -0,0 +1,36 @@
+use std::{fmt, sync::Arc};
+
+/// Advisory conditions a `FrostGroup` reports to its observer
+///
+/// Warnings never change the outcome of an operation; they let operators
+/// notice configurations or inputs that work but are worth a second look.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GroupWarning {
+    /// A message larger than `threshold` bytes was signed directly; prehash
+    /// it and use `FrostGroup::round_2_sign_prehashed` instead
+    LargeMessage { len: usize, threshold: usize },
+    /// The group has a threshold of 1, so any one of its `max_signers`
+    /// participants can sign alone
+    SingleSigner { max_signers: usize },
+}
+
+/// Callback receiving a group's warnings; shared by clones of the group
+#[derive(Clone)]
+pub(crate) struct Observer(Arc<dyn Fn(&GroupWarning) + Send + Sync>);
+
+impl Observer {
+    pub(crate) fn new(
+        f: impl Fn(&GroupWarning) + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(f))
+    }
+
+    pub(crate) fn warn(&self, warning: &GroupWarning) { (self.0)(warning) }
+}
+
+impl fmt::Debug for Observer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Observer")
+    }
+}
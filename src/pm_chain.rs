@@ -1,16 +1,39 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    time::{Duration, Instant},
+};
 
-use anyhow::{Result, bail};
+use anyhow::{Result, anyhow, bail};
 use bc_crypto::{hkdf_hmac_sha256, sha256};
-use dcbor::{CBOREncodable, Date};
-use frost_ed25519::{Identifier, round1::SigningCommitments};
+use dcbor::{CBOR, CBOREncodable, Date};
+use frost_ed25519::{
+    Identifier, Signature, VerifyingKey, round1::SigningCommitments,
+};
 use provenance_mark::{ProvenanceMark, ProvenanceMarkResolution};
+use sha2::{Digest, Sha256};
 
-use crate::{FrostGroup, FrostGroupConfig};
+use crate::{
+    CeremonyState, ChainManifest, FrostError, FrostGroup, FrostGroupConfig,
+    GenesisProof, MarkRecord, PrecommitReceipt, ProposalCheck,
+    PublicGroupSummary, ValidationReport,
+    message::{
+        MarkInfo, MessagePurpose, encode_date, framed_message, normalize_date,
+        obj_hash,
+    },
+};
 
 /// Check if the candidate nextKey matches what the previous mark committed to
 /// This is done by recomputing the previous mark's hash with the candidate
 /// nextKey
+///
+/// Audit use: a mark stores only its hash, which covers `key || next_key ||
+/// chain_id || seq || date || info`, and never the next key itself (the
+/// provenance-mark crate does not expose it). An auditor therefore cannot
+/// extract the committed next key, but can confirm a claimed one: given the
+/// next mark's `key()` (or a key rederived from revealed commitments), this
+/// returns `true` exactly when `prev` committed to it. Any other candidate
+/// produces a different hash.
 pub fn prev_commitment_matches(
     prev: &ProvenanceMark,
     candidate_next_key: &[u8],
@@ -28,10 +51,334 @@ pub fn prev_commitment_matches(
     Ok(mark.hash() == prev.hash())
 }
 
+/// Verify that `next` correctly follows `prev` without access to chain state
+/// Reconstructs the Round-2 message from `next`'s fields, verifies the group
+/// signature over it, and checks the chain_id, resolution, seq, date, and
+/// precedence invariants between the two marks
+pub fn verify_append(
+    group: &FrostGroup,
+    prev: &ProvenanceMark,
+    next: &ProvenanceMark,
+    signature: &Signature,
+) -> Result<()> {
+    verify_link(group.config(), group.verifying_key(), prev, next, signature)
+}
+
+/// Verify published marks against signatures archived separately, e.g. in
+/// an operator's signature registry
+/// `signatures[i]` is the group signature behind `marks[i]`: the genesis
+/// signature over `message_0` first, from which the genesis key must derive,
+/// then one per append, each checked as in `verify_append`. Chains whose
+/// genesis binds a predecessor key, chain, or identity need
+/// `FrostPmChain::replay_verify` instead
+pub fn verify_chain_with_signatures(
+    group: &FrostGroup,
+    marks: &[ProvenanceMark],
+    signatures: &[Signature],
+) -> Result<()> {
+    let (Some(genesis), Some(genesis_signature)) =
+        (marks.first(), signatures.first())
+    else {
+        bail!("cannot verify an empty chain");
+    };
+    if signatures.len() != marks.len() {
+        bail!(
+            "expected {} signatures, got {}",
+            marks.len(),
+            signatures.len()
+        );
+    }
+    if !genesis.is_genesis() {
+        bail!("first mark is not a genesis mark");
+    }
+    let message_0 = FrostPmChain::message_0(
+        group.config(),
+        genesis.res(),
+        genesis.date(),
+        genesis.info(),
+    );
+    group.verify_as(
+        MessagePurpose::Genesis,
+        message_0.as_bytes(),
+        genesis_signature,
+    )?;
+    let key_0 = FrostPmChain::kdf_genesis(
+        message_0.as_bytes(),
+        genesis_signature,
+        None,
+        genesis.res().link_length(),
+    )?;
+    if key_0 != genesis.key() {
+        bail!("genesis key does not match the genesis signature");
+    }
+    FrostPmChain::verify_against_public_summary(
+        &group.public_summary(),
+        marks,
+        &signatures[1..],
+    )
+}
+
+/// Get the seq that must follow `seq`
+/// Marks checked here may come from anyone, so a predecessor at `u32::MAX`
+/// is an error rather than an overflow
+fn successor_seq(seq: u32) -> Result<u32> {
+    seq.checked_add(1)
+        .ok_or_else(|| anyhow!("no mark can follow seq {}", seq))
+}
+
+/// `verify_append` against a group's config and verifying key alone
+fn verify_link(
+    config: &FrostGroupConfig,
+    verifying_key: &VerifyingKey,
+    prev: &ProvenanceMark,
+    next: &ProvenanceMark,
+    signature: &Signature,
+) -> Result<()> {
+    if next.chain_id() != prev.chain_id() {
+        bail!("chain_id mismatch between marks");
+    }
+    if next.res() != prev.res() {
+        bail!("resolution mismatch between marks");
+    }
+    let expected = successor_seq(prev.seq())?;
+    if next.seq() != expected {
+        bail!("sequence gap: expected seq {}, got {}", expected, next.seq());
+    }
+    if next.date() < prev.date() {
+        bail!("date monotonicity violated");
+    }
+
+    let message = FrostPmChain::next_message(
+        config,
+        next.res(),
+        next.seq(),
+        next.date(),
+        next.info(),
+    );
+    let framed = framed_message(MessagePurpose::Mark, message.as_bytes());
+    verifying_key.verify(&framed, signature)?;
+
+    if !prev.precedes(next) {
+        bail!(
+            "Chain integrity check failed: next mark does not follow previous mark"
+        );
+    }
+
+    Ok(())
+}
+
+/// Version of the derivation from a commitments root to a mark's key
+/// Fixed for a chain at genesis; changing it invalidates existing chains
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KdfVersion {
+    /// Binds the chain id, seq, and commitments root (`kdf_next`)
+    #[default]
+    V1,
+    /// Additionally binds the group's roster hash (`kdf_next_v2`), so every
+    /// key is tied to the specific group governance
+    V2,
+}
+
+/// Per-phase durations of a single `FrostPmChain::append_mark_timed` call
+/// Phases are measured back to back, so they sum to `total`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AppendTimings {
+    /// Computing the commitments root for this mark
+    pub root: Duration,
+    /// Deriving the key and checking it against the previous mark
+    pub kdf: Duration,
+    /// Building the message and verifying the group signature
+    pub verify: Duration,
+    /// Deriving next_key from the next commitments
+    pub precommit: Duration,
+    /// Constructing and storing the new mark
+    pub finalize: Duration,
+    /// Total time spent in the append
+    pub total: Duration,
+}
+
+impl AppendTimings {
+    /// Sum of all individual phase durations
+    pub fn phases_sum(&self) -> Duration {
+        self.root + self.kdf + self.verify + self.precommit + self.finalize
+    }
+}
+
+/// Lifetime counters of a `FrostPmChain` since it was created
+/// Complements the per-append `AppendTimings` with cumulative totals
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats {
+    /// Marks successfully appended (genesis is not counted)
+    pub marks_appended: u64,
+    /// Group signatures that verified during appends, including those of
+    /// appends that then failed
+    pub signatures_verified: u64,
+    /// Time spent computing commitments roots and deriving keys
+    pub derivation_time: Duration,
+}
+
+/// Records contiguous phase durations; does nothing when disabled
+struct PhaseTimer {
+    timings: AppendTimings,
+    start: Option<Instant>,
+    last: Option<Instant>,
+}
+
+impl PhaseTimer {
+    fn disabled() -> Self {
+        Self { timings: AppendTimings::default(), start: None, last: None }
+    }
+
+    fn enabled() -> Self {
+        let now = Instant::now();
+        Self {
+            timings: AppendTimings::default(),
+            start: Some(now),
+            last: Some(now),
+        }
+    }
+
+    /// Attribute the time since the previous lap to the selected phase
+    fn lap(&mut self, phase: impl FnOnce(&mut AppendTimings) -> &mut Duration) {
+        if let Some(last) = self.last {
+            let now = Instant::now();
+            *phase(&mut self.timings) = now - last;
+            self.last = Some(now);
+        }
+    }
+
+    fn finish(mut self) -> AppendTimings {
+        if let (Some(start), Some(last)) = (self.start, self.last) {
+            self.timings.total = last - start;
+        }
+        self.timings
+    }
+}
+
+/// The hash-chain skeleton of one mark, for custom verification tooling
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// Sequence number of the mark
+    pub seq: u32,
+    /// The mark's key
+    pub key: Vec<u8>,
+    /// The key the mark committed to for the following mark
+    pub next_key: Vec<u8>,
+    /// The mark's date
+    pub date: Date,
+}
+
+/// One mark of a `FrostPmChain::compact_proof`: its link and info, enough to
+/// rebuild the mark and so recompute its hash
+/// The hash also covers the date and info, so those travel with the keys
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkProof {
+    /// The mark's hash-chain skeleton
+    pub link: Link,
+    /// The mark's info
+    pub info: Option<CBOR>,
+}
+
+/// Verify that the mark hashing to `to_hash` descends from `from`
+/// Rebuilds each mark of `proof` (see `FrostPmChain::compact_proof`) in turn,
+/// checking that its predecessor committed to its key, and finally that the
+/// last rebuilt mark has hash `to_hash`. Only `from` and `to_hash` are
+/// trusted: this shows an unbroken key chain between them, but not that the
+/// intermediate dates and info are the originals, and checks no signatures
+pub fn verify_compact_proof(
+    from: &ProvenanceMark,
+    to_hash: &[u8],
+    proof: &[LinkProof],
+) -> Result<()> {
+    if proof.is_empty() {
+        bail!("empty compact proof");
+    }
+    let mut prev = from.clone();
+    for step in proof {
+        let link = &step.link;
+        let expected = successor_seq(prev.seq())?;
+        if link.seq != expected {
+            bail!(
+                "compact proof gap: expected seq {}, got {}",
+                expected,
+                link.seq
+            );
+        }
+        if !prev_commitment_matches(&prev, &link.key)? {
+            bail!("mark {} does not commit to mark {}", prev.seq(), link.seq);
+        }
+        prev = ProvenanceMark::new(
+            from.res(),
+            link.key.clone(),
+            link.next_key.clone(),
+            from.chain_id().to_vec(),
+            link.seq,
+            link.date,
+            step.info.clone(),
+        )?;
+    }
+    if prev.hash() != to_hash {
+        bail!("compact proof does not end at the target mark");
+    }
+    Ok(())
+}
+
+/// Optional genesis inputs beyond the required ones
+struct GenesisOptions<'a> {
+    predecessor_key: Option<VerifyingKey>,
+    predecessor_mark_hash: Option<&'a [u8]>,
+    identity: Option<&'a [u8]>,
+    kdf_version: KdfVersion,
+}
+
+/// The caller-supplied fields of a mark being appended
+struct NewMark<I> {
+    seq: u32,
+    date: Date,
+    info: Option<I>,
+}
+
+/// Callback invoked with each successfully appended mark
+pub type AppendHook = Box<dyn FnMut(&ProvenanceMark) + Send>;
+
+/// Hooks registered with `FrostPmChain::on_append`, in registration order
+#[derive(Default)]
+struct AppendHooks(Vec<AppendHook>);
+
+impl fmt::Debug for AppendHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AppendHooks({})", self.0.len())
+    }
+}
+
 #[derive(Debug)]
 pub struct FrostPmChain {
     group: FrostGroup,
     last_mark: ProvenanceMark,
+    /// The key `last_mark` committed to for the following mark
+    next_key: Vec<u8>,
+    predecessor_key: Option<VerifyingKey>,
+    /// Hash of the prior chain's last mark, bound into the genesis message
+    predecessor_mark_hash: Option<Vec<u8>>,
+    /// SHA-256 of the charter bytes bound verbatim into the genesis message
+    charter_hash: [u8; 32],
+    /// Derivation used for every key after key_0
+    kdf_version: KdfVersion,
+    /// Links and info of retained marks, oldest first, when history mode is
+    /// enabled
+    history: Option<Vec<(Link, Option<CBOR>)>>,
+    /// Archival records of marks appended while history mode is enabled
+    records: Vec<MarkRecord>,
+    /// Callbacks notified after every successful append
+    hooks: AppendHooks,
+    /// Lifetime counters
+    stats: Stats,
+    /// Whether every append must use exactly the next contiguous seq
+    strict_contiguous: bool,
+    /// Idempotency key of the append that minted `last_mark`, if it had one
+    last_idempotency_key: Option<[u8; 16]>,
 }
 
 impl FrostPmChain {
@@ -45,33 +392,552 @@ impl FrostPmChain {
     fn next_seq(&self) -> u32 { self.last_mark.seq() + 1 }
 
     /// Get a reference to the underlying FROST group
+    /// The group is never lent out mutably, so its charter stays the one the
+    /// genesis message bound:
+    ///
+    /// ```compile_fail,E0599
+    /// # fn edit(mut chain: frost_pm_test::FrostPmChain) {
+    /// let _ = chain.group_mut();
+    /// # }
+    /// ```
     pub fn group(&self) -> &FrostGroup { &self.group }
 
+    /// Get the chain's lifetime counters
+    pub fn stats(&self) -> &Stats { &self.stats }
+
+    /// Get the key derivation version fixed at genesis
+    pub fn kdf_version(&self) -> KdfVersion { self.kdf_version }
+
+    /// Whether appends must use contiguous seqs (the default)
+    pub fn strict_contiguous(&self) -> bool { self.strict_contiguous }
+
+    /// Allow or forbid gaps in seq for `append_mark_at_seq`
+    /// Sparse chains do not interoperate with provenance-mark's sequence
+    /// validation (`precedes`, `is_sequence_valid`), nor with `verify_append`,
+    /// `replay_verify`, or compact proofs, which all expect `+1` increments.
+    pub fn set_strict_contiguous(&mut self, strict: bool) {
+        self.strict_contiguous = strict;
+    }
+
+    /// Get the most recently created mark
+    pub fn last_mark(&self) -> &ProvenanceMark { &self.last_mark }
+
+    /// Get the info bound into the most recently created mark
+    pub fn current_info(&self) -> Option<CBOR> { self.last_mark.info() }
+
+    /// Get the number of marks in the chain, including genesis
+    /// Derived in O(1) from the last mark's seq, so it is authoritative even
+    /// though the chain does not retain its full history (unless the chain
+    /// is sparse; see `set_strict_contiguous`)
+    pub fn chain_length(&self) -> u64 { self.last_mark.seq() as u64 + 1 }
+
+    /// Verify a chain using only a group's public summary
+    /// `signatures[i]` is the group signature that appended `marks[i + 1]`;
+    /// the genesis signature is checked when the chain is created, so only
+    /// the genesis invariant is checked for `marks[0]`. Each link is checked
+    /// as in `verify_append`, so no secret material is needed
+    pub fn verify_against_public_summary(
+        summary: &PublicGroupSummary,
+        marks: &[ProvenanceMark],
+        signatures: &[Signature],
+    ) -> Result<()> {
+        let Some(genesis) = marks.first() else {
+            bail!("cannot verify an empty chain");
+        };
+        if !genesis.is_genesis() {
+            bail!("first mark is not a genesis mark");
+        }
+        if signatures.len() != marks.len() - 1 {
+            bail!(
+                "expected {} append signatures, got {}",
+                marks.len() - 1,
+                signatures.len()
+            );
+        }
+        for (pair, signature) in marks.windows(2).zip(signatures) {
+            verify_link(
+                summary.config(),
+                summary.verifying_key(),
+                &pair[0],
+                &pair[1],
+                signature,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Audit the marks following a checkpoint, without the genesis proof
+    /// Trust is rooted in the checkpoint: its embedded summary supplies the
+    /// group key, so it (or its hash) must come from a source the verifier
+    /// trusts. `checkpoint_signature` is checked under that key, which shows
+    /// only that the checkpoint is self-consistent. `signatures[i]` is the
+    /// group signature that appended `marks[i]`, and each link is checked as
+    /// in `verify_append`. Returns the embedded summary.
+    pub fn verify_from_checkpoint(
+        checkpoint: &ProvenanceMark,
+        checkpoint_signature: &Signature,
+        marks: &[ProvenanceMark],
+        signatures: &[Signature],
+    ) -> Result<PublicGroupSummary> {
+        let Some(MarkInfo::Checkpoint(summary)) =
+            checkpoint.info().map(MarkInfo::from)
+        else {
+            bail!("mark {} is not a checkpoint", checkpoint.seq());
+        };
+        let message = Self::next_message(
+            summary.config(),
+            checkpoint.res(),
+            checkpoint.seq(),
+            checkpoint.date(),
+            checkpoint.info(),
+        );
+        summary.verify_as(
+            MessagePurpose::Mark,
+            message.as_bytes(),
+            checkpoint_signature,
+        )?;
+        if signatures.len() != marks.len() {
+            bail!(
+                "expected {} append signatures, got {}",
+                marks.len(),
+                signatures.len()
+            );
+        }
+        let mut prev = checkpoint;
+        for (mark, signature) in marks.iter().zip(signatures) {
+            verify_link(
+                summary.config(),
+                summary.verifying_key(),
+                prev,
+                mark,
+                signature,
+            )?;
+            prev = mark;
+        }
+        Ok(*summary)
+    }
+
+    /// Summarize the chain in a compact manifest for auditors
+    /// The manifest carries the whitespace-normalized charter digest (see
+    /// `FrostGroupConfig::charter_digest`)
+    pub fn export_chain_manifest(&self) -> ChainManifest {
+        ChainManifest::new(
+            self.chain_id().to_vec(),
+            self.res(),
+            self.group.fingerprint(),
+            self.last_mark.seq(),
+            self.last_mark.date(),
+            self.group.config().charter_digest(),
+        )
+    }
+
+    /// Re-verify a whole chain from archived artifacts alone
+    /// Starting from the genesis proof, rebuilds the genesis message, checks
+    /// its signature, and re-derives key_0; then for each record re-derives
+    /// the mark's key from its Round-1 commitments, checks that the previous
+    /// mark committed to that key, and checks the record as `verify_append`
+    /// does (signature, seq, date monotonicity, precedence). Nothing reported
+    /// by the operator is trusted. Dates are read back from the marks, so
+    /// they must survive the resolution's date precision unchanged
+    pub fn replay_verify(
+        group: &FrostGroup,
+        genesis: &GenesisProof,
+        records: &[MarkRecord],
+    ) -> Result<()> {
+        let mark_0 = genesis.mark();
+        if !mark_0.is_genesis() {
+            bail!("genesis proof does not hold a genesis mark");
+        }
+        let message_0 = Self::genesis_message(
+            group.config(),
+            mark_0.res(),
+            mark_0.date(),
+            mark_0.info(),
+            genesis.predecessor_key(),
+            genesis.predecessor_chain(),
+        );
+        group.verify_as(
+            MessagePurpose::Genesis,
+            message_0.as_bytes(),
+            genesis.signature(),
+        )?;
+        let key_0 = Self::kdf_genesis(
+            message_0.as_bytes(),
+            genesis.signature(),
+            genesis.identity(),
+            mark_0.res().link_length(),
+        )?;
+        if key_0 != mark_0.key() {
+            bail!("genesis key does not match the genesis proof");
+        }
+
+        let mut prev = mark_0;
+        for record in records {
+            let mark = record.mark();
+            Self::validate_commitments(group, record.commitments())?;
+            let key = Self::derive_next_key(
+                genesis.kdf_version(),
+                group.config(),
+                prev.chain_id(),
+                successor_seq(prev.seq())?,
+                Self::commitments_root(record.commitments()),
+                prev.res(),
+            )?;
+            if key != mark.key() {
+                bail!(
+                    "mark {} key does not match its archived commitments",
+                    mark.seq()
+                );
+            }
+            if !prev_commitment_matches(prev, &key)? {
+                bail!(
+                    "mark {} key was not committed to by the previous mark",
+                    mark.seq()
+                );
+            }
+            verify_append(group, prev, mark, record.signature())?;
+            prev = mark;
+        }
+        Ok(())
+    }
+
+    /// Rebuild a chain from its archive, positioned at its tip
+    /// Runs `replay_verify` over `genesis` and `records`, then checks that
+    /// `next` (the receipt for the commitments precommitted by the tip) is
+    /// valid and derives the key the tip committed to, so the next append can
+    /// proceed. The rebuilt chain is in history mode with every mark retained.
+    pub fn from_records(
+        group: FrostGroup,
+        genesis: &GenesisProof,
+        records: &[MarkRecord],
+        next: &PrecommitReceipt,
+    ) -> Result<Self> {
+        Self::replay_verify(&group, genesis, records)?;
+        let marks: Vec<&ProvenanceMark> = std::iter::once(genesis.mark())
+            .chain(records.iter().map(MarkRecord::mark))
+            .collect();
+        let tip = *marks.last().expect("genesis mark");
+
+        let expected = successor_seq(tip.seq())?;
+        if next.seq() != expected {
+            bail!("receipt is for seq {}, expected {}", next.seq(), expected);
+        }
+        next.validate(&group)?;
+        let next_key = Self::derive_next_key(
+            genesis.kdf_version(),
+            group.config(),
+            tip.chain_id(),
+            next.seq(),
+            next.root(),
+            tip.res(),
+        )?;
+        if !prev_commitment_matches(tip, &next_key)? {
+            bail!("receipt does not match the key the tip committed to");
+        }
+
+        // Each mark's next key is its successor's key; the tip's comes from
+        // the receipt
+        let next_keys = marks[1..]
+            .iter()
+            .map(|mark| mark.key().to_vec())
+            .chain(std::iter::once(next_key.clone()));
+        let history = marks
+            .iter()
+            .zip(next_keys)
+            .map(|(mark, next_key)| {
+                let link = Link {
+                    seq: mark.seq(),
+                    key: mark.key().to_vec(),
+                    next_key,
+                    date: mark.date(),
+                };
+                (link, mark.info())
+            })
+            .collect();
+
+        Ok(Self {
+            last_mark: tip.clone(),
+            next_key,
+            predecessor_key: genesis.predecessor_key().copied(),
+            predecessor_mark_hash: genesis
+                .predecessor_chain()
+                .map(<[u8]>::to_vec),
+            charter_hash: sha256(group.charter().as_bytes()),
+            kdf_version: genesis.kdf_version(),
+            history: Some(history),
+            records: records.to_vec(),
+            hooks: AppendHooks::default(),
+            stats: Stats::default(),
+            strict_contiguous: true,
+            last_idempotency_key: None,
+            group,
+        })
+    }
+
+    /// Branch the chain at its current tip into an independent chain
+    /// For simulation and testing of alternate histories. Both branches share
+    /// the group and the tip's precommitted Round-1 commitments, so the first
+    /// mark on each branch must use those same commitments; the branches
+    /// produce genuinely different marks from there on (different info, dates,
+    /// and next commitments), and only one can be the canonical history.
+    /// Signing divergent messages with the same Round-1 nonces reuses them,
+    /// which exposes the signers' shares, so never fork a production chain.
+    pub fn fork(&self) -> FrostPmChain {
+        FrostPmChain {
+            group: self.group.clone(),
+            last_mark: self.last_mark.clone(),
+            next_key: self.next_key.clone(),
+            predecessor_key: self.predecessor_key,
+            predecessor_mark_hash: self.predecessor_mark_hash.clone(),
+            charter_hash: self.charter_hash,
+            kdf_version: self.kdf_version,
+            history: self.history.clone(),
+            records: self.records.clone(),
+            hooks: AppendHooks::default(),
+            stats: Stats::default(),
+            strict_contiguous: self.strict_contiguous,
+            last_idempotency_key: self.last_idempotency_key,
+        }
+    }
+
+    /// Start retaining the link of every mark from the current tip onwards
+    /// The chain otherwise keeps only its last mark; earlier marks cannot be
+    /// recovered once history mode is enabled
+    pub fn enable_history(&mut self) {
+        if self.history.is_none() {
+            let tip = (self.tip_link(), self.last_mark.info());
+            self.history = Some(vec![tip]);
+        }
+    }
+
+    /// Get the archival record of every mark appended since history mode was
+    /// enabled, oldest first
+    /// Together with the genesis proof and the tip's precommit receipt these
+    /// rebuild the chain with `from_records`. Empty unless history mode is
+    /// enabled (see `enable_history`)
+    pub fn export_records(&self) -> Vec<MarkRecord> { self.records.clone() }
+
+    /// Register a callback invoked with every mark appended from now on,
+    /// e.g. to maintain an external index without polling
+    /// Hooks run after the chain's state is updated, so they never see a mark
+    /// from a failed append; they are not carried over by `fork`
+    pub fn on_append(&mut self, hook: AppendHook) { self.hooks.0.push(hook); }
+
+    /// Iterate the links of retained marks, oldest first
+    /// Empty unless history mode is enabled (see `enable_history`)
+    pub fn iter_links(&self) -> impl Iterator<Item = Link> + '_ {
+        self.history.iter().flatten().map(|(link, _)| link.clone())
+    }
+
+    /// Get the sequence numbers revoked by retained revocation marks, in the
+    /// order they were revoked
+    /// Empty unless history mode is enabled (see `enable_history`)
+    pub fn revoked_sequences(&self) -> Vec<u32> {
+        self.history
+            .iter()
+            .flatten()
+            .filter_map(|(_, info)| match info.clone().map(MarkInfo::from) {
+                Some(MarkInfo::Revocation { target_seq, .. }) => {
+                    Some(target_seq)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Get the sequence numbers of retained heartbeat marks, oldest first
+    /// Empty unless history mode is enabled (see `enable_history`)
+    pub fn heartbeat_sequences(&self) -> Vec<u32> {
+        self.retained_heartbeats().map(|link| link.seq).collect()
+    }
+
+    /// Get the date of the latest retained heartbeat mark, if any
+    /// Consumers can compare it to the current date to spot a stalled or
+    /// abandoned chain. Requires history mode (see `enable_history`)
+    pub fn last_heartbeat_at(&self) -> Option<Date> {
+        self.retained_heartbeats().last().map(|link| link.date)
+    }
+
+    /// Links of retained marks whose info is a `MarkInfo::Heartbeat`
+    fn retained_heartbeats(&self) -> impl Iterator<Item = &Link> + '_ {
+        self.history.iter().flatten().filter_map(|(link, info)| {
+            match info.clone().map(MarkInfo::from) {
+                Some(MarkInfo::Heartbeat { .. }) => Some(link),
+                _ => None,
+            }
+        })
+    }
+
+    /// Prove that retained mark `j` descends from retained mark `i`
+    /// Returns one `LinkProof` per mark after `i` up to and including `j`,
+    /// for `verify_compact_proof`. Every intermediate mark is needed, since
+    /// each commits only to its successor's key. Requires history mode
+    pub fn compact_proof(&self, i: u32, j: u32) -> Result<Vec<LinkProof>> {
+        if i >= j {
+            bail!("compact proof needs i < j, got {} and {}", i, j);
+        }
+        let Some(history) = &self.history else {
+            bail!("compact proofs require history mode");
+        };
+        let first = history.first().map_or(0, |(link, _)| link.seq);
+        if i < first || j > self.last_mark.seq() {
+            bail!(
+                "marks {}..={} are not retained (history covers {}..={})",
+                i,
+                j,
+                first,
+                self.last_mark.seq()
+            );
+        }
+        let proof = history[(i - first) as usize + 1..=(j - first) as usize]
+            .iter()
+            .map(|(link, info)| LinkProof {
+                link: link.clone(),
+                info: info.clone(),
+            })
+            .collect();
+        Ok(proof)
+    }
+
+    /// Find the first seq at which `other_marks` diverges from this chain
+    /// Compares mark hashes at every seq both histories hold, so `Ok(None)`
+    /// means one is a prefix of the other (or they do not overlap).
+    /// `other_marks` must be in ascending seq order. Requires history mode
+    pub fn diff_against(
+        &self,
+        other_marks: &[ProvenanceMark],
+    ) -> Result<Option<u32>> {
+        let Some(history) = &self.history else {
+            bail!("diffing chains requires history mode");
+        };
+        let first = history.first().map_or(0, |(link, _)| link.seq);
+        for other in other_marks {
+            let Some(index) = other.seq().checked_sub(first) else {
+                continue;
+            };
+            let Some((link, info)) = history.get(index as usize) else {
+                break;
+            };
+            let mark = ProvenanceMark::new(
+                self.res(),
+                link.key.clone(),
+                link.next_key.clone(),
+                self.chain_id().to_vec(),
+                link.seq,
+                link.date,
+                info.clone(),
+            )?;
+            if mark.hash() != other.hash() {
+                return Ok(Some(other.seq()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The link of the chain's most recent mark
+    fn tip_link(&self) -> Link {
+        Link {
+            seq: self.last_mark.seq(),
+            key: self.last_mark.key().to_vec(),
+            next_key: self.next_key.clone(),
+            date: self.last_mark.date(),
+        }
+    }
+
+    /// Get the predecessor verifying key bound into the genesis message, if
+    /// the chain was created as a continuation of a prior signing identity
+    pub fn predecessor_key(&self) -> Option<&VerifyingKey> {
+        self.predecessor_key.as_ref()
+    }
+
+    /// Get the hash of the prior chain's last mark bound into the genesis
+    /// message, if this chain was started as that chain's continuation
+    pub fn predecessor_chain(&self) -> Option<&[u8]> {
+        self.predecessor_mark_hash.as_deref()
+    }
+
     /// Create a genesis message for a group
+    /// The charter is length-prefixed (`Charter: <len>:<charter>`) so its
+    /// content can never be mistaken for the structural fields around it
     pub fn message_0(
         config: &FrostGroupConfig,
         res: ProvenanceMarkResolution,
         date: Date,
         info: Option<impl CBOREncodable>,
+    ) -> String {
+        Self::message_0_with_predecessor(config, res, date, info, None)
+    }
+
+    /// Create a genesis message for a group that also binds a predecessor
+    /// verifying key, linking the new chain to a prior signing identity
+    pub fn message_0_with_predecessor(
+        config: &FrostGroupConfig,
+        res: ProvenanceMarkResolution,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        predecessor_key: Option<&VerifyingKey>,
+    ) -> String {
+        Self::genesis_message(config, res, date, info, predecessor_key, None)
+    }
+
+    /// Create a genesis message for a group that also binds the hash of a
+    /// prior chain's last mark, linking the new chain to the one it continues
+    pub fn message_0_with_predecessor_chain(
+        config: &FrostGroupConfig,
+        res: ProvenanceMarkResolution,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        predecessor_mark_hash: &[u8],
+    ) -> String {
+        Self::genesis_message(
+            config,
+            res,
+            date,
+            info,
+            None,
+            Some(predecessor_mark_hash),
+        )
+    }
+
+    /// Build a genesis message with every optional predecessor binding
+    fn genesis_message(
+        config: &FrostGroupConfig,
+        res: ProvenanceMarkResolution,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        predecessor_key: Option<&VerifyingKey>,
+        predecessor_mark_hash: Option<&[u8]>,
     ) -> String {
         let participant_names: Vec<String> =
             config.participants().keys().cloned().collect();
-        let info_data = if let Some(ref info_val) = info {
-            info_val.to_cbor_data()
-        } else {
-            Vec::new()
-        };
-        let info_hash = hex::encode(sha256(&info_data));
-        format!(
-            "FROST Provenance Mark Chain\nResolution: {}, Threshold: {} of {}\nParticipants: {}\nCharter: {}\nDate: {}\nInfo Hash: {}",
+        let info_hash = hex::encode(obj_hash(info.as_ref()));
+        let mut message = format!(
+            "FROST Provenance Mark Chain\nResolution: {}, Threshold: {} of {}\nParticipants: {}\nCharter: {}:{}\nDate: {}\nInfo Hash: {}",
             res,
             config.min_signers(),
             participant_names.len(),
             participant_names.join(", "),
+            config.charter().len(),
             config.charter(),
-            date,
+            hex::encode(encode_date(&date)),
             info_hash
-        )
+        );
+        if let Some(key) = predecessor_key {
+            let key_bytes = key
+                .serialize()
+                .expect("serialize predecessor verifying key");
+            message.push_str(&format!(
+                "\nPredecessor Key: {}",
+                hex::encode(key_bytes)
+            ));
+        }
+        if let Some(hash) = predecessor_mark_hash {
+            message.push_str(&format!(
+                "\nPredecessor Chain: {}",
+                hex::encode(hash)
+            ));
+        }
+        message
     }
 
     pub fn message_next(
@@ -79,28 +945,48 @@ impl FrostPmChain {
         date: Date,
         info: Option<impl CBOREncodable>,
     ) -> String {
-        let info_data = if let Some(ref info_val) = info {
-            info_val.to_cbor_data()
-        } else {
-            Vec::new()
-        };
-        let info_hash = hex::encode(sha256(&info_data));
+        self.message_at_seq(self.next_seq(), date, info)
+    }
+
+    /// Build the Round-2 message for a mark appended with
+    /// `append_mark_at_seq`
+    pub fn message_at_seq(
+        &self,
+        seq: u32,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+    ) -> String {
+        Self::next_message(self.group.config(), self.res(), seq, date, info)
+    }
+
+    /// Build the Round-2 message for a non-genesis mark from its fields
+    fn next_message(
+        config: &FrostGroupConfig,
+        res: ProvenanceMarkResolution,
+        seq: u32,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+    ) -> String {
+        let participant_names: Vec<&str> =
+            config.participants().keys().map(String::as_str).collect();
+        let info_hash = hex::encode(obj_hash(info.as_ref()));
         format!(
-            "FROST Provenance Mark Chain\nResolution: {}, Threshold: {} of {}\nParticipants: {}\nCharter: {}\nSequence: {}\nDate: {}\nInfo Hash: {}",
-            self.res(),
-            self.group.min_signers(),
-            self.group.max_signers(),
-            self.group.participant_names().join(", "),
-            self.group.charter(),
-            self.next_seq(),
-            date,
+            "FROST Provenance Mark Chain\nResolution: {}, Threshold: {} of {}\nParticipants: {}\nCharter: {}:{}\nSequence: {}\nDate: {}\nInfo Hash: {}",
+            res,
+            config.min_signers(),
+            config.max_signers(),
+            participant_names.join(", "),
+            config.charter().len(),
+            config.charter(),
+            seq,
+            hex::encode(encode_date(&date)),
             info_hash
         )
     }
 
     // Create a new chain with its genesis mark: derive key_0, precommit seq=1,
-    // then finalize Mark 0. Returns the chain, genesis mark, and initial
-    // precommit data for seq=1
+    // then finalize Mark 0. Returns the chain, genesis mark, and the receipt
+    // for the seq=1 precommitment, which callers should archive
     pub fn new_chain(
         res: ProvenanceMarkResolution,
         date: Date,
@@ -108,40 +994,199 @@ impl FrostPmChain {
         group: FrostGroup,
         message_0_signature: frost_ed25519::Signature,
         commitments_1: &BTreeMap<Identifier, SigningCommitments>,
-    ) -> Result<(Self, ProvenanceMark)> {
+    ) -> Result<(Self, ProvenanceMark, PrecommitReceipt)> {
+        Self::new_chain_with_predecessor(
+            res,
+            date,
+            info,
+            group,
+            message_0_signature,
+            commitments_1,
+            None,
+        )
+    }
+
+    /// Create a new chain whose genesis message binds a predecessor verifying
+    /// key (see `message_0_with_predecessor`). The genesis signature must
+    /// cover the message including that key.
+    pub fn new_chain_with_predecessor(
+        res: ProvenanceMarkResolution,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        group: FrostGroup,
+        message_0_signature: frost_ed25519::Signature,
+        commitments_1: &BTreeMap<Identifier, SigningCommitments>,
+        predecessor_key: Option<VerifyingKey>,
+    ) -> Result<(Self, ProvenanceMark, PrecommitReceipt)> {
+        Self::genesis(
+            res,
+            date,
+            info,
+            group,
+            message_0_signature,
+            commitments_1,
+            GenesisOptions {
+                predecessor_key,
+                predecessor_mark_hash: None,
+                identity: None,
+                kdf_version: KdfVersion::V1,
+            },
+        )
+    }
+
+    /// Create a new chain continuing a prior one, binding the hash of its
+    /// last mark into the genesis message (see
+    /// `message_0_with_predecessor_chain`), e.g. after rotating to a new
+    /// group. The genesis signature must cover the message including it
+    pub fn new_chain_with_predecessor_chain(
+        predecessor_mark_hash: &[u8],
+        res: ProvenanceMarkResolution,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        group: FrostGroup,
+        message_0_signature: frost_ed25519::Signature,
+        commitments_1: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<(Self, ProvenanceMark, PrecommitReceipt)> {
+        Self::genesis(
+            res,
+            date,
+            info,
+            group,
+            message_0_signature,
+            commitments_1,
+            GenesisOptions {
+                predecessor_key: None,
+                predecessor_mark_hash: Some(predecessor_mark_hash),
+                identity: None,
+                kdf_version: KdfVersion::V1,
+            },
+        )
+    }
+
+    /// Create a new chain whose chain id reflects a caller-chosen identity
+    /// (e.g. derived from a product SKU). The identity is folded into the
+    /// HKDF that produces key_0 as a domain separator, so the chain id is
+    /// deterministic in the identity while `chain_id == key_0` still holds.
+    pub fn new_chain_with_identity(
+        identity: &[u8],
+        res: ProvenanceMarkResolution,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        group: FrostGroup,
+        message_0_signature: frost_ed25519::Signature,
+        commitments_1: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<(Self, ProvenanceMark, PrecommitReceipt)> {
+        Self::genesis(
+            res,
+            date,
+            info,
+            group,
+            message_0_signature,
+            commitments_1,
+            GenesisOptions {
+                predecessor_key: None,
+                predecessor_mark_hash: None,
+                identity: Some(identity),
+                kdf_version: KdfVersion::V1,
+            },
+        )
+    }
+
+    /// Create a new chain deriving its keys with `kdf_version`
+    /// `KdfVersion::V2` binds the group's roster hash into every key after
+    /// key_0; verifiers must be told the version (see
+    /// `GenesisProof::with_kdf_version`)
+    pub fn new_chain_with_kdf_version(
+        kdf_version: KdfVersion,
+        res: ProvenanceMarkResolution,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        group: FrostGroup,
+        message_0_signature: frost_ed25519::Signature,
+        commitments_1: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<(Self, ProvenanceMark, PrecommitReceipt)> {
+        Self::genesis(
+            res,
+            date,
+            info,
+            group,
+            message_0_signature,
+            commitments_1,
+            GenesisOptions {
+                predecessor_key: None,
+                predecessor_mark_hash: None,
+                identity: None,
+                kdf_version,
+            },
+        )
+    }
+
+    fn genesis(
+        res: ProvenanceMarkResolution,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        group: FrostGroup,
+        message_0_signature: frost_ed25519::Signature,
+        commitments_1: &BTreeMap<Identifier, SigningCommitments>,
+        options: GenesisOptions,
+    ) -> Result<(Self, ProvenanceMark, PrecommitReceipt)> {
+        let GenesisOptions {
+            predecessor_key,
+            predecessor_mark_hash,
+            identity,
+            kdf_version,
+        } = options;
+        group.config().check_resolution(res)?;
+        let date = normalize_date(&date);
         let link_len = res.link_length();
 
         // 1. Derive key_0 (and thus id) using the provided genesis message
         //    signature
         // Build M0 from group configuration including charter and participant
         // names
-        let genesis_msg =
-            Self::message_0(group.config(), res, date, info.clone());
+        let genesis_msg = Self::genesis_message(
+            group.config(),
+            res,
+            date,
+            info.clone(),
+            predecessor_key.as_ref(),
+            predecessor_mark_hash,
+        );
         let m0 = genesis_msg.as_bytes();
 
         // Verify the provided signature against the genesis message
-        group.verify(m0, &message_0_signature)?;
+        group.verify_as(MessagePurpose::Genesis, m0, &message_0_signature)?;
 
         let key_0 =
-            hkdf_hmac_sha256(&message_0_signature.serialize()?, m0, link_len);
+            Self::kdf_genesis(m0, &message_0_signature, identity, link_len)?;
 
         // id == key_0 (genesis invariant)
         let id = key_0.clone();
 
         // 2. Use provided precommit data for seq=1
         // The client has already performed Round-1 commit for the next sequence
+        // It must come from a threshold quorum of participants, or no quorum
+        // could ever reproduce next_key_0 and the chain would be stuck at seq 1
+        Self::validate_commitments(&group, commitments_1)?;
 
         // Compute Root_1 = commitments_root(&commitments_map)
         let root_1 = Self::commitments_root(commitments_1);
 
         // Compute next_key_0 = derive_link_from_root(res, id, 1, Root_1)
-        let next_key_0 = Self::kdf_next(&id, 1, root_1, res);
+        let next_key_0 = Self::derive_next_key(
+            kdf_version,
+            group.config(),
+            &id,
+            1,
+            root_1,
+            res,
+        )?;
 
         // 3. Finalize M⟨0⟩ with key_0 and this next_key_0
         let mark_0 = ProvenanceMark::new(
             res,
             key_0,
-            next_key_0,
+            next_key_0.clone(),
             id.clone(),
             0,
             date,
@@ -149,9 +1194,25 @@ impl FrostPmChain {
         )?;
 
         // 4. Create the chain with the genesis mark
-        let chain = Self { group, last_mark: mark_0.clone() };
+        let charter_hash = sha256(group.charter().as_bytes());
+        let chain = Self {
+            group,
+            last_mark: mark_0.clone(),
+            next_key: next_key_0,
+            predecessor_key,
+            predecessor_mark_hash: predecessor_mark_hash.map(<[u8]>::to_vec),
+            charter_hash,
+            kdf_version,
+            history: None,
+            records: Vec::new(),
+            hooks: AppendHooks::default(),
+            stats: Stats::default(),
+            strict_contiguous: true,
+            last_idempotency_key: None,
+        };
 
-        Ok((chain, mark_0))
+        let receipt = PrecommitReceipt::new(1, commitments_1.clone());
+        Ok((chain, mark_0, receipt))
     }
 
     /// Append the next mark using precommitted Round-1 commitments
@@ -166,30 +1227,334 @@ impl FrostPmChain {
         message_next_signature: frost_ed25519::Signature,
         next_commitments: &BTreeMap<Identifier, SigningCommitments>,
     ) -> Result<ProvenanceMark> {
-        // Check date monotonicity against the last mark's date
+        self.append_mark_inner(
+            NewMark { seq: self.next_seq(), date, info },
+            commitments,
+            message_next_signature,
+            next_commitments,
+            true,
+            &mut PhaseTimer::disabled(),
+        )
+    }
+
+    /// `append_mark` without re-verifying `message_next_signature`
+    /// Only for callers that have *just* verified that exact signature over
+    /// `message_next(date, info)` as `MessagePurpose::Mark`, e.g. in a tight
+    /// coordinator loop; it saves one EdDSA verification per mark. Every
+    /// other check still runs, but an unverified signature is never
+    /// detected, so prefer `append_mark` whenever in doubt.
+    pub fn append_mark_trusted(
+        &mut self,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        message_next_signature: frost_ed25519::Signature,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<ProvenanceMark> {
+        self.append_mark_inner(
+            NewMark { seq: self.next_seq(), date, info },
+            commitments,
+            message_next_signature,
+            next_commitments,
+            false,
+            &mut PhaseTimer::disabled(),
+        )
+    }
+
+    /// `append_mark`, safe to retry: if the last mark was minted with the same
+    /// `idempotency_key`, it is returned again and the chain is unchanged
+    /// Only the most recent append is remembered, and a retry returns that
+    /// mark whatever its other arguments, so use a fresh key per logical mark.
+    pub fn append_mark_idempotent(
+        &mut self,
+        idempotency_key: [u8; 16],
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        message_next_signature: frost_ed25519::Signature,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<ProvenanceMark> {
+        if self.last_idempotency_key == Some(idempotency_key) {
+            return Ok(self.last_mark.clone());
+        }
+        let mark = self.append_mark(
+            date,
+            info,
+            commitments,
+            message_next_signature,
+            next_commitments,
+        )?;
+        self.last_idempotency_key = Some(idempotency_key);
+        Ok(mark)
+    }
+
+    /// Append a mark with a caller-chosen seq, e.g. one encoding a timestamp
+    /// `seq` must exceed the tip's, and while the chain is
+    /// `strict_contiguous` must be exactly the next seq. The signature must
+    /// cover `message_at_seq(seq, ..)`. Keys stay bound to the mark's
+    /// position, so the key committed to by the tip is still the one used.
+    pub fn append_mark_at_seq(
+        &mut self,
+        seq: u32,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        message_signature: frost_ed25519::Signature,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<ProvenanceMark> {
+        if seq <= self.last_mark.seq() {
+            bail!(
+                "seq {} does not follow the tip's seq {}",
+                seq,
+                self.last_mark.seq()
+            );
+        }
+        if self.strict_contiguous && seq != self.next_seq() {
+            bail!(
+                "sequence gap: expected seq {}, got {}",
+                self.next_seq(),
+                seq
+            );
+        }
+        if seq == u32::MAX {
+            bail!("seq {} leaves no room for a following mark", seq);
+        }
+        self.append_mark_inner(
+            NewMark { seq, date, info },
+            commitments,
+            message_signature,
+            next_commitments,
+            true,
+            &mut PhaseTimer::disabled(),
+        )
+    }
+
+    /// Append a mark revoking the earlier mark `target_seq`
+    /// The revocation is an ordinary mark whose info is a
+    /// `MarkInfo::Revocation`; the signature must cover `message_next` over
+    /// that info. Earlier marks stay in the chain unchanged.
+    pub fn append_revocation(
+        &mut self,
+        date: Date,
+        target_seq: u32,
+        reason: Option<String>,
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        message_next_signature: frost_ed25519::Signature,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<ProvenanceMark> {
+        if target_seq >= self.next_seq() {
+            bail!(
+                "cannot revoke seq {}: only marks before seq {} exist",
+                target_seq,
+                self.next_seq()
+            );
+        }
+        self.append_mark(
+            date,
+            Some(MarkInfo::Revocation { target_seq, reason }),
+            commitments,
+            message_next_signature,
+            next_commitments,
+        )
+    }
+
+    /// Append a checkpoint mark embedding the group's public summary
+    /// The mark's info is a `MarkInfo::Checkpoint` of the group's public
+    /// summary; the signature must cover `message_next` over that info. Later
+    /// marks can then be audited from it alone with `verify_from_checkpoint`.
+    pub fn append_checkpoint(
+        &mut self,
+        date: Date,
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        message_next_signature: frost_ed25519::Signature,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<ProvenanceMark> {
+        let info = MarkInfo::Checkpoint(Box::new(self.group.public_summary()));
+        self.append_mark(
+            date,
+            Some(info),
+            commitments,
+            message_next_signature,
+            next_commitments,
+        )
+    }
+
+    /// Append a heartbeat mark proving continued control of the chain
+    /// The mark's info is `MarkInfo::Heartbeat { nonce }`, with the nonce
+    /// drawn by the caller (e.g. `MarkInfo::heartbeat(rng)`) before signing;
+    /// the signature must cover `message_next` over that info
+    pub fn append_heartbeat(
+        &mut self,
+        date: Date,
+        nonce: [u8; 16],
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        message_next_signature: frost_ed25519::Signature,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<ProvenanceMark> {
+        self.append_mark(
+            date,
+            Some(MarkInfo::Heartbeat { nonce }),
+            commitments,
+            message_next_signature,
+            next_commitments,
+        )
+    }
+
+    /// Begin a resumable append: validate the Round-1 commitments for this
+    /// mark and the next one, and capture everything needed to finish the
+    /// append once the Round-2 signature over `state.message()` is available
+    pub fn begin_append(
+        &self,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        signers: &[&str],
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<CeremonyState> {
+        let date = normalize_date(&date);
+        if date < self.last_mark.date() {
+            bail!("date monotonicity violated");
+        }
+        Self::validate_commitments(&self.group, commitments)?;
+        Self::validate_commitments(&self.group, next_commitments)?;
+        Self::check_fresh_commitments(commitments, next_commitments)?;
+
+        let mut signer_ids = BTreeSet::new();
+        for &signer in signers {
+            signer_ids.insert(self.group.name_to_id(signer)?);
+        }
+        if !commitments.keys().eq(signer_ids.iter()) {
+            bail!("commitments do not match the declared signers");
+        }
+
+        let message = self.message_next(date, info.clone());
+        Ok(CeremonyState::new(
+            self.next_seq(),
+            signers.iter().map(|s| s.to_string()).collect(),
+            commitments.clone(),
+            next_commitments.clone(),
+            message,
+            date,
+            info.map(|i| i.to_cbor()),
+        ))
+    }
+
+    /// Complete an append started with `begin_append`, possibly after the
+    /// state was persisted and restored
+    pub fn complete_append(
+        &mut self,
+        state: &CeremonyState,
+        message_next_signature: frost_ed25519::Signature,
+    ) -> Result<ProvenanceMark> {
+        if state.seq() != self.next_seq() {
+            bail!(
+                "stale ceremony state: expected seq {}, got {}",
+                self.next_seq(),
+                state.seq()
+            );
+        }
+        self.append_mark(
+            state.date(),
+            state.info().cloned(),
+            state.commitments(),
+            message_next_signature,
+            state.next_commitments(),
+        )
+    }
+
+    /// Same as `append_mark`, but also returns a per-phase timing breakdown
+    /// Intended for performance tuning of high-throughput minting
+    pub fn append_mark_timed(
+        &mut self,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        message_next_signature: frost_ed25519::Signature,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<(ProvenanceMark, AppendTimings)> {
+        let mut timer = PhaseTimer::enabled();
+        let mark = self.append_mark_inner(
+            NewMark { seq: self.next_seq(), date, info },
+            commitments,
+            message_next_signature,
+            next_commitments,
+            true,
+            &mut timer,
+        )?;
+        Ok((mark, timer.finish()))
+    }
+
+    fn append_mark_inner(
+        &mut self,
+        mark: NewMark<impl CBOREncodable>,
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        message_next_signature: frost_ed25519::Signature,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+        verify_signature: bool,
+        timer: &mut PhaseTimer,
+    ) -> Result<ProvenanceMark> {
+        let NewMark { seq, date, info } = mark;
+        // Check date monotonicity against the last mark's date, in UTC
+        let date = normalize_date(&date);
         if date < self.last_mark.date() {
             bail!("date monotonicity violated");
         }
 
-        let seq = self.next_seq();
+        // The charter is bound into the genesis message and must not drift;
+        // the group's config has no public mutators, so this is defense in
+        // depth against a chain assembled with a mismatched group
+        if sha256(self.group.charter().as_bytes()) != self.charter_hash {
+            bail!(FrostError::CharterChangedAfterGenesis);
+        }
+
+        // Both commitment sets must come from at least a threshold of known
+        // participants, or the derived keys could not be reproduced
+        Self::validate_commitments(&self.group, commitments)?;
+        Self::validate_commitments(&self.group, next_commitments)?;
+        Self::check_fresh_commitments(commitments, next_commitments)?;
+
+        let derivation_start = Instant::now();
         let root = Self::commitments_root(commitments);
+        timer.lap(|t| &mut t.root);
 
         // 2. Derive key from the receipt's root (which matches the commitments)
-        let key = Self::kdf_next(self.chain_id(), seq, root, self.res());
+        // The tip derived it for the seq following its own, which differs from
+        // `seq` only in a sparse chain
+        let key = Self::derive_next_key(
+            self.kdf_version,
+            self.group.config(),
+            self.chain_id(),
+            self.next_seq(),
+            root,
+            self.res(),
+        )?;
 
         // 3. Verify that this key matches what the previous mark committed to
         if !prev_commitment_matches(&self.last_mark, &key)? {
+            if self.consumed_by_tip(root)? {
+                bail!(FrostError::NextKeyAlreadyCommitted);
+            }
             bail!(
                 "Chain integrity check failed: key doesn't match previous mark's next_key"
             );
         }
+        let mut derivation_time = derivation_start.elapsed();
+        timer.lap(|t| &mut t.kdf);
 
         // 4. Build message for Round-2 signing (standard PM message format)
-        let message = Self::message_next(self, date, info.clone());
+        let message = self.message_at_seq(seq, date, info.clone());
 
-        // 5. VERIFY the provided signature under the group verifying key
-        self.group
-            .verify(message.as_bytes(), &message_next_signature)?;
+        // 5. VERIFY the provided signature under the group verifying key,
+        //    unless the caller has just done so (`append_mark_trusted`)
+        if verify_signature {
+            self.group.verify_as(
+                MessagePurpose::Mark,
+                message.as_bytes(),
+                &message_next_signature,
+            )?;
+            self.stats.signatures_verified += 1;
+        }
+        timer.lap(|t| &mut t.verify);
 
         // 6. BEFORE finalizing this mark's hash, use provided commitments for
         //    seq+1
@@ -198,23 +1563,210 @@ impl FrostPmChain {
         let next_seq = seq + 1;
 
         // Use client-provided commitments for next sequence
+        let derivation_start = Instant::now();
         let next_root = Self::commitments_root(next_commitments);
 
-        let next_key = Self::kdf_next(&chain_id, next_seq, next_root, res);
+        let next_key = Self::derive_next_key(
+            self.kdf_version,
+            self.group.config(),
+            &chain_id,
+            next_seq,
+            next_root,
+            res,
+        )?;
+        derivation_time += derivation_start.elapsed();
+        timer.lap(|t| &mut t.precommit);
 
         // 7. Use key and next_key to create the mark
-        let next_mark =
-            ProvenanceMark::new(res, key, next_key, chain_id, seq, date, info)?;
+        let next_mark = ProvenanceMark::new(
+            res,
+            key,
+            next_key.clone(),
+            chain_id,
+            seq,
+            date,
+            info,
+        )?;
 
         // 8. Store the new mark
         self.last_mark = next_mark.clone();
+        self.next_key = next_key;
+        self.last_idempotency_key = None;
+        if let Some(mut history) = self.history.take() {
+            history.push((self.tip_link(), self.last_mark.info()));
+            self.history = Some(history);
+            self.records.push(MarkRecord::new(
+                next_mark.clone(),
+                message_next_signature,
+                commitments.clone(),
+            ));
+        }
+        self.stats.marks_appended += 1;
+        self.stats.derivation_time += derivation_time;
+        timer.lap(|t| &mut t.finalize);
+
+        // 9. Notify hooks only once the chain has committed to the mark
+        for hook in &mut self.hooks.0 {
+            hook(&next_mark);
+        }
 
         Ok(next_mark)
     }
 
+    /// Run every check `append_mark` would on a proposed mark, without
+    /// appending it
+    /// For vetting a proposal from another party: all checks run even after
+    /// one fails, and the report lists each outcome
+    pub fn validate_proposed_mark(
+        &self,
+        date: Date,
+        info: Option<impl CBOREncodable>,
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        message_next_signature: frost_ed25519::Signature,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        let date = normalize_date(&date);
+        report.record(
+            ProposalCheck::DateMonotonic,
+            if date < self.last_mark.date() {
+                Err(anyhow!("date monotonicity violated"))
+            } else {
+                Ok(())
+            },
+        );
+        report.record(
+            ProposalCheck::CharterUnchanged,
+            if sha256(self.group.charter().as_bytes()) != self.charter_hash {
+                Err(FrostError::CharterChangedAfterGenesis.into())
+            } else {
+                Ok(())
+            },
+        );
+        report.record(
+            ProposalCheck::CommitmentQuorum,
+            Self::validate_commitments(&self.group, commitments),
+        );
+        report.record(
+            ProposalCheck::NextCommitmentQuorum,
+            Self::validate_commitments(&self.group, next_commitments),
+        );
+        report.record(
+            ProposalCheck::FreshNextCommitments,
+            Self::check_fresh_commitments(commitments, next_commitments),
+        );
+        report.record(
+            ProposalCheck::KeyLinkage,
+            self.check_linkage(commitments),
+        );
+        let message = self.message_next(date, info);
+        report.record(
+            ProposalCheck::Signature,
+            self.group.verify_as(
+                MessagePurpose::Mark,
+                message.as_bytes(),
+                &message_next_signature,
+            ),
+        );
+        report
+    }
+
+    /// Check that `commitments` derive the key the tip committed to
+    fn check_linkage(
+        &self,
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<()> {
+        let root = Self::commitments_root(commitments);
+        let key = Self::derive_next_key(
+            self.kdf_version,
+            self.group.config(),
+            self.chain_id(),
+            self.next_seq(),
+            root,
+            self.res(),
+        )?;
+        if !prev_commitment_matches(&self.last_mark, &key)? {
+            if self.consumed_by_tip(root)? {
+                bail!(FrostError::NextKeyAlreadyCommitted);
+            }
+            bail!("key doesn't match previous mark's next_key");
+        }
+        Ok(())
+    }
+
+    /// Whether commitments with `root` are the ones the tip itself was
+    /// appended with, i.e. a concurrent proposer's append already consumed
+    /// them. Only recognizes a tip appended at its predecessor's next seq
+    fn consumed_by_tip(&self, root: [u8; 32]) -> Result<bool> {
+        if self.last_mark.is_genesis() {
+            return Ok(false);
+        }
+        let key = Self::derive_next_key(
+            self.kdf_version,
+            self.group.config(),
+            self.chain_id(),
+            self.last_mark.seq(),
+            root,
+            self.res(),
+        )?;
+        Ok(key == self.last_mark.key())
+    }
+
+    /// Check that a commitment set meets the threshold and only contains
+    /// commitments from group participants
+    fn validate_commitments(
+        group: &FrostGroup,
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<()> {
+        let required = group.min_signers();
+        if commitments.len() < required {
+            bail!(FrostError::InsufficientCommitments {
+                required,
+                got: commitments.len(),
+            });
+        }
+        let participant_ids = group.config().participant_ids();
+        if commitments.keys().any(|id| !participant_ids.contains(id)) {
+            bail!(FrostError::UnknownCommitmentParticipant);
+        }
+        Ok(())
+    }
+
+    /// Reject next commitments that are the ones being consumed: their nonces
+    /// are spent by this append, so no quorum could sign the following mark
+    fn check_fresh_commitments(
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+        next_commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> Result<()> {
+        if Self::commitments_root(commitments)
+            == Self::commitments_root(next_commitments)
+        {
+            bail!(FrostError::StaleNextCommitments);
+        }
+        Ok(())
+    }
+
+    /// Derive key_0 from the genesis message and its signature
+    fn kdf_genesis(
+        m0: &[u8],
+        signature: &Signature,
+        identity: Option<&[u8]>,
+        link_len: usize,
+    ) -> Result<Vec<u8>> {
+        // A caller-chosen identity is appended to the HKDF salt under its own
+        // domain tag; without one the salt is M0 alone
+        let mut salt = m0.to_vec();
+        if let Some(identity) = identity {
+            salt.extend_from_slice(b"PM:v1/identity");
+            salt.extend_from_slice(identity);
+        }
+        Ok(hkdf_hmac_sha256(&signature.serialize()?, &salt, link_len))
+    }
+
     /// Compute a deterministic root over Round-1 commitment map
-    /// This provides deterministic key derivation from commitment sets
-    fn commitments_root(
+    /// This provides deterministic key derivation from commitment sets. Part of
+    /// the chain protocol: changing its output invalidates existing chains
+    pub fn commitments_root(
         commitments: &BTreeMap<Identifier, SigningCommitments>,
     ) -> [u8; 32] {
         let mut buf = Vec::with_capacity(commitments.len() * 100);
@@ -239,10 +1791,34 @@ impl FrostPmChain {
         sha256(&buf)
     }
 
+    /// `commitments_root`, hashing each length-prefixed commitment as it is
+    /// encoded rather than buffering them all first
+    /// Byte-identical to `commitments_root`; peak memory stays constant in the
+    /// number of signers, which matters for very large groups
+    pub fn commitments_root_streaming(
+        commitments: &BTreeMap<Identifier, SigningCommitments>,
+    ) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        for (id, sc) in commitments {
+            let id_bytes =
+                bincode::serde::encode_to_vec(id, bincode::config::standard())
+                    .expect("serialize identifier");
+            let sc_bytes =
+                bincode::serde::encode_to_vec(sc, bincode::config::standard())
+                    .expect("serialize signing commitments");
+            hasher.update((id_bytes.len() as u16).to_be_bytes());
+            hasher.update(&id_bytes);
+            hasher.update((sc_bytes.len() as u16).to_be_bytes());
+            hasher.update(&sc_bytes);
+        }
+        hasher.finalize().into()
+    }
+
     /// KDF for nextKey / key derivation from commitment root
     /// Domain separation and binding to chain + seq
-    /// Returns the correct length for the given resolution
-    fn kdf_next(
+    /// Returns the correct length for the given resolution. Part of the chain
+    /// protocol: changing its output invalidates existing chains
+    pub fn kdf_next(
         chain_id: &[u8],
         seq: u32,
         root: [u8; 32],
@@ -257,4 +1833,145 @@ impl FrostPmChain {
         let len = res.link_length();
         hash[..len].to_vec()
     }
+
+    /// `KdfVersion::V2` key derivation: as `kdf_next`, under its own domain
+    /// tag and additionally binding the group's `roster_hash`. Part of the
+    /// chain protocol: changing its output invalidates existing chains
+    pub fn kdf_next_v2(
+        chain_id: &[u8],
+        seq: u32,
+        root: [u8; 32],
+        roster_hash: [u8; 32],
+        res: ProvenanceMarkResolution,
+    ) -> Vec<u8> {
+        let mut msg = b"PM:v2/next".to_vec();
+        msg.extend_from_slice(&roster_hash);
+        msg.extend_from_slice(chain_id);
+        msg.extend_from_slice(&seq.to_be_bytes());
+        msg.extend_from_slice(&root);
+        let hash = sha256(&msg);
+        hash[..res.link_length()].to_vec()
+    }
+
+    /// Derive a key after key_0 with the given KDF version
+    fn derive_next_key(
+        kdf_version: KdfVersion,
+        config: &FrostGroupConfig,
+        chain_id: &[u8],
+        seq: u32,
+        root: [u8; 32],
+        res: ProvenanceMarkResolution,
+    ) -> Result<Vec<u8>> {
+        Ok(match kdf_version {
+            KdfVersion::V1 => Self::kdf_next(chain_id, seq, root, res),
+            KdfVersion::V2 => Self::kdf_next_v2(
+                chain_id,
+                seq,
+                root,
+                config.roster_hash()?,
+                res,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+
+    use super::*;
+
+    /// The group's charter cannot change through the public API, so the
+    /// drift check is exercised by corrupting the hash bound at genesis
+    #[test]
+    fn charter_drift_blocks_appends() -> Result<()> {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let config = FrostGroupConfig::new(
+            2,
+            &["Alice", "Bob", "Charlie"],
+            "Charter drift".to_string(),
+        )?;
+        let res = ProvenanceMarkResolution::Medium;
+        let date_0 = Date::from_ymd(2025, 1, 1);
+        let message_0 =
+            FrostPmChain::message_0(&config, res, date_0, None::<&str>);
+        let group = FrostGroup::new_with_trusted_dealer(config, &mut rng)?;
+        let signers = &["Alice", "Bob"];
+        let (commitments_0, nonces_0) =
+            group.round_1_commit(signers, &mut rng)?;
+        let signature_0 = group.round_2_sign_as(
+            MessagePurpose::Genesis,
+            signers,
+            &commitments_0,
+            &nonces_0,
+            message_0.as_bytes(),
+        )?;
+        let (commitments, nonces) = group.round_1_commit(signers, &mut rng)?;
+        let (mut chain, ..) = FrostPmChain::new_chain(
+            res,
+            date_0,
+            None::<&str>,
+            group,
+            signature_0,
+            &commitments,
+        )?;
+
+        let date = Date::from_ymd(2025, 1, 2);
+        let message = chain.message_next(date, None::<&str>);
+        let signature = chain.group().round_2_sign_as(
+            MessagePurpose::Mark,
+            signers,
+            &commitments,
+            &nonces,
+            message.as_bytes(),
+        )?;
+        let (next_commitments, _) =
+            chain.group().round_1_commit(signers, &mut rng)?;
+
+        // The raw bytes are bound, so a whitespace-only edit would be drift
+        // even though the normalized digest ignores it
+        let bound = chain.charter_hash;
+        assert_eq!(bound, sha256(b"Charter drift"));
+        let padded = FrostGroupConfig::new(
+            2,
+            &["Alice", "Bob", "Charlie"],
+            "Charter drift \r\n".to_string(),
+        )?;
+        assert_eq!(
+            padded.charter_digest(),
+            chain.group().config().charter_digest()
+        );
+        chain.charter_hash = sha256(padded.charter().as_bytes());
+        let error = chain
+            .append_mark(
+                date,
+                None::<&str>,
+                &commitments,
+                signature,
+                &next_commitments,
+            )
+            .unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<FrostError>(),
+            Some(&FrostError::CharterChangedAfterGenesis)
+        );
+        let report = chain.validate_proposed_mark(
+            date,
+            None::<&str>,
+            &commitments,
+            signature,
+            &next_commitments,
+        );
+        assert_eq!(report.failures(), [ProposalCheck::CharterUnchanged]);
+
+        chain.charter_hash = bound;
+        chain.append_mark(
+            date,
+            None::<&str>,
+            &commitments,
+            signature,
+            &next_commitments,
+        )?;
+        Ok(())
+    }
 }
@@ -0,0 +1,27 @@
+//! The types most programs need, in one import
+//!
+//! ```
+//! use frost_pm_test::prelude::*;
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let config = FrostGroupConfig::two_of_three(
+//!     &["Alice", "Bob", "Charlie"],
+//!     "Release signing".to_string(),
+//! )?;
+//! let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+//!
+//! let signers = ["Alice", "Charlie"];
+//! let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+//! let signature =
+//!     group.round_2_sign(&signers, &commitments, &nonces, b"v1.0.0")?;
+//! group.verify(b"v1.0.0", &signature)?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::{
+    FrostError, FrostGroup, FrostGroupConfig, FrostPmChain, PrecommitReceipt,
+    PublicGroupSummary,
+    message::{MarkInfo, MessagePurpose},
+    rand_core::{CryptoRng, OsRng, RngCore},
+};
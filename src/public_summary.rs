@@ -0,0 +1,253 @@
+use std::{collections::BTreeMap, fmt};
+
+use anyhow::{Result, anyhow, bail};
+use bc_crypto::sha256;
+use dcbor::{CBOR, Map};
+use frost_ed25519::{Identifier, Signature, SigningPackage, VerifyingKey};
+
+use crate::{
+    FrostError, FrostGroupConfig,
+    frost_group_config::DEFAULT_MAX_CHARTER_LEN,
+    message::{MessagePurpose, aad_message, framed_message},
+};
+
+/// The public half of a FROST group: its configuration and verifying key
+/// Everything an auditor needs to check signatures and rebuild signed
+/// messages, with no secret key material
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PublicGroupSummary {
+    config: FrostGroupConfig,
+    verifying_key: VerifyingKey,
+}
+
+impl PublicGroupSummary {
+    /// Create a summary from a published config and group verifying key
+    pub fn new(config: FrostGroupConfig, verifying_key: VerifyingKey) -> Self {
+        Self { config, verifying_key }
+    }
+
+    /// Get a reference to the group configuration
+    pub fn config(&self) -> &FrostGroupConfig { &self.config }
+
+    /// Get the group's verifying key (public key)
+    pub fn verifying_key(&self) -> &VerifyingKey { &self.verifying_key }
+
+    /// Get the group's fingerprint; equal to `FrostGroup::fingerprint` for
+    /// the group this summary describes
+    pub fn fingerprint(&self) -> [u8; 32] {
+        key_fingerprint(&self.verifying_key)
+    }
+
+    /// The canonical encoding a group signs to attest to this summary
+    /// A domain tag, the verifying key, and the config's roster hash, which
+    /// covers the threshold, charter, and every participant's id and name
+    pub fn attestation_message(&self) -> Result<Vec<u8>> {
+        let key_bytes = self
+            .verifying_key
+            .serialize()
+            .expect("serialize group verifying key");
+        let mut buf = b"PM:v1/attest".to_vec();
+        buf.extend_from_slice(&key_bytes);
+        buf.extend_from_slice(&self.config.roster_hash()?);
+        Ok(buf)
+    }
+
+    /// Verify a signature against an application message using the group's
+    /// public key
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<()> {
+        self.verify_as(MessagePurpose::Application, message, signature)
+    }
+
+    /// Verify a signature made with `FrostGroup::round_2_sign_with_aad`
+    pub fn verify_with_aad(
+        &self,
+        aad: &[u8],
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<()> {
+        self.verify_as(
+            MessagePurpose::Aad,
+            &aad_message(aad, message)?,
+            signature,
+        )
+    }
+
+    /// Verify a signature made for `purpose` (see `FrostGroup::verify_as`)
+    pub fn verify_as(
+        &self,
+        purpose: MessagePurpose,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<()> {
+        let framed = framed_message(purpose, message);
+        Ok(self.verifying_key.verify(&framed, signature)?)
+    }
+
+    /// Verify a signature against an archived signing package
+    /// The package's message is the exact (already framed) byte string the
+    /// signers saw, so no purpose is supplied here. Its commitments must name
+    /// a threshold of the group's participants and must produce the
+    /// signature's group commitment `R`, which ties them to this signature
+    /// rather than to any threshold-sized set.
+    pub fn verify_with_package(
+        &self,
+        package: &SigningPackage,
+        signature: &Signature,
+    ) -> Result<()> {
+        let participant_ids = self.config.participant_ids();
+        let commitments = package.signing_commitments();
+        if commitments.keys().any(|id| !participant_ids.contains(id)) {
+            bail!(FrostError::UnknownCommitmentParticipant);
+        }
+        let required = self.config.min_signers();
+        if commitments.len() < required {
+            bail!(FrostError::InsufficientCommitments {
+                required,
+                got: commitments.len(),
+            });
+        }
+        let binding_factors = frost_core::compute_binding_factor_list(
+            package,
+            &self.verifying_key,
+            &[],
+        )?;
+        let group_commitment =
+            frost_core::compute_group_commitment(package, &binding_factors)?;
+        if group_commitment.to_element() != *signature.R() {
+            bail!(FrostError::UnboundCommitments);
+        }
+        Ok(self.verifying_key.verify(package.message(), signature)?)
+    }
+}
+
+/// A one-line description for people, using participants' display names in
+/// identifier order, e.g. `2-of-3 group (Dr. Alice Smith, Bob, Charlie):
+/// charter`
+impl fmt::Display for PublicGroupSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let config = &self.config;
+        let names = config.participant_names_in_id_order();
+        let names: Vec<&str> = names
+            .iter()
+            .map(|name| config.participant_display_name(name))
+            .collect();
+        write!(
+            f,
+            "{}-of-{} group ({}): {}",
+            config.min_signers(),
+            config.max_signers(),
+            names.join(", "),
+            config.charter()
+        )
+    }
+}
+
+/// SHA-256 over a domain tag and a group verifying key
+pub(crate) fn key_fingerprint(verifying_key: &VerifyingKey) -> [u8; 32] {
+    let key_bytes = verifying_key
+        .serialize()
+        .expect("serialize group verifying key");
+    let mut buf = b"PM:v1/group".to_vec();
+    buf.extend_from_slice(&key_bytes);
+    sha256(&buf)
+}
+
+/// Encoded as the map `{"threshold": n, "participants": {name: h'id'},
+/// "charter": "…", "key": h'…'}`, e.g. for a checkpoint mark's info
+impl From<&PublicGroupSummary> for CBOR {
+    fn from(summary: &PublicGroupSummary) -> Self {
+        let config = summary.config();
+        let mut participants = Map::new();
+        for (name, id) in config.participants() {
+            let id = CBOR::to_byte_string(id.serialize());
+            participants.insert(name.as_str(), id);
+        }
+        let key_bytes = summary
+            .verifying_key
+            .serialize()
+            .expect("serialize group verifying key");
+        let mut map = Map::new();
+        map.insert("threshold", config.min_signers() as u64);
+        map.insert("participants", participants);
+        map.insert("charter", config.charter());
+        map.insert("key", CBOR::to_byte_string(key_bytes));
+        map.into()
+    }
+}
+
+/// Decode a summary, revalidating the config
+/// Charter length limits, resolution policy, and display names are local
+/// settings and are not encoded; the decoded config allows at least the
+/// default length.
+impl TryFrom<CBOR> for PublicGroupSummary {
+    type Error = anyhow::Error;
+
+    fn try_from(cbor: CBOR) -> Result<Self> {
+        let map = cbor.try_into_map()?;
+        if map.len() != 4 {
+            bail!("group summary has unexpected fields");
+        }
+        let field = |key: &str| {
+            map.get::<_, CBOR>(key)
+                .ok_or_else(|| anyhow!("group summary is missing {}", key))
+        };
+        let min_signers = u64::try_from(field("threshold")?)?;
+        let mut participants = BTreeMap::new();
+        for (name, id) in field("participants")?.try_into_map()?.iter() {
+            let id =
+                Identifier::deserialize(&id.clone().try_into_byte_string()?)?;
+            participants.insert(name.clone().try_into_text()?, id);
+        }
+        let charter = field("charter")?.try_into_text()?;
+        let verifying_key =
+            VerifyingKey::deserialize(&field("key")?.try_into_byte_string()?)?;
+        let max_charter_len = charter.len().max(DEFAULT_MAX_CHARTER_LEN);
+        let config = FrostGroupConfig::from_participants(
+            usize::try_from(min_signers)?,
+            participants,
+            charter,
+            max_charter_len,
+        )?;
+        Ok(Self::new(config, verifying_key))
+    }
+}
+
+/// A group's signature over its own public summary
+/// Produced by `FrostGroup::self_attest`, typically right after DKG, so the
+/// group's composition can later be proven from the attestation alone
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupAttestation {
+    summary: PublicGroupSummary,
+    signature: Signature,
+}
+
+impl GroupAttestation {
+    /// Assemble an attestation from a summary and the group's signature
+    pub fn new(summary: PublicGroupSummary, signature: Signature) -> Self {
+        Self { summary, signature }
+    }
+
+    /// Get the attested summary
+    pub fn summary(&self) -> &PublicGroupSummary { &self.summary }
+
+    /// Get the group's signature over the summary
+    pub fn signature(&self) -> &Signature { &self.signature }
+}
+
+/// Check that an attestation was signed by the group it describes, returning
+/// the attested summary
+/// This proves a threshold of the key's holders endorsed the roster; it does
+/// not by itself establish which key a relying party should trust.
+pub fn verify_attestation(
+    attestation: &GroupAttestation,
+) -> Result<PublicGroupSummary> {
+    let summary = &attestation.summary;
+    summary.verify_as(
+        MessagePurpose::Attestation,
+        &summary.attestation_message()?,
+        &attestation.signature,
+    )?;
+    Ok(summary.clone())
+}
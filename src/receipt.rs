@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Result, bail};
+use frost_ed25519::{Identifier, round1::SigningCommitments};
+
+use crate::{FrostError, FrostGroup, FrostPmChain};
+
+/// Commitment map keyed by participant identifier
+type Commitments = BTreeMap<Identifier, SigningCommitments>;
+
+/// Receipt for a set of Round-1 commitments precommitted for a future mark
+///
+/// Carries the seq of the mark whose key the commitments fix, the
+/// commitments, the identifiers they claim to come from, and the root that
+/// key is derived from. A receipt received from a coordinator
+/// should pass `validate` against the group before it is trusted.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrecommitReceipt {
+    seq: u32,
+    ids: Vec<Identifier>,
+    commitments: Commitments,
+    root: [u8; 32],
+}
+
+impl PrecommitReceipt {
+    /// Issue a receipt for `commitments`, computing its ids and root
+    pub fn new(seq: u32, commitments: Commitments) -> Self {
+        let ids = commitments.keys().copied().collect();
+        let root = FrostPmChain::commitments_root(&commitments);
+        Self { seq, ids, commitments, root }
+    }
+
+    /// Reassemble a receipt received from elsewhere, without checking it
+    pub fn from_parts(
+        seq: u32,
+        ids: Vec<Identifier>,
+        commitments: Commitments,
+        root: [u8; 32],
+    ) -> Self {
+        Self { seq, ids, commitments, root }
+    }
+
+    /// Get the seq of the mark whose key these commitments fix
+    pub fn seq(&self) -> u32 { self.seq }
+
+    /// Get the identifiers of the participants that precommitted
+    pub fn ids(&self) -> &[Identifier] { &self.ids }
+
+    /// Get the precommitted Round-1 commitments
+    pub fn commitments(&self) -> &Commitments { &self.commitments }
+
+    /// Get the commitments root the next key is derived from
+    pub fn root(&self) -> [u8; 32] { self.root }
+
+    /// Check that the receipt is self-consistent and acceptable to `group`:
+    /// the root matches the commitments, the ids are exactly the commitment
+    /// keys, and a threshold of known participants precommitted
+    pub fn validate(&self, group: &FrostGroup) -> Result<()> {
+        if FrostPmChain::commitments_root(&self.commitments) != self.root {
+            bail!("receipt root does not match its commitments");
+        }
+        if !self.ids.iter().eq(self.commitments.keys()) {
+            bail!("receipt ids do not match its commitments");
+        }
+        let participant_ids = group.config().participant_ids();
+        if self.ids.iter().any(|id| !participant_ids.contains(id)) {
+            bail!(FrostError::UnknownCommitmentParticipant);
+        }
+        let required = group.min_signers();
+        if self.ids.len() < required {
+            bail!(FrostError::InsufficientCommitments {
+                required,
+                got: self.ids.len(),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use anyhow::{Result, bail};
+use bc_crypto::sha256;
+use frost_ed25519::keys::KeyPackage;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::FrostError;
+
+/// Magic prefix identifying a FROST share envelope
+const MAGIC: &[u8; 4] = b"FPMS";
+
+/// Current envelope format version
+const VERSION: u8 = 2;
+
+/// Envelope version predating the frost version tag; its key packages were
+/// always written by `FROST_KEY_FORMAT`
+const VERSION_UNTAGGED: u8 = 1;
+
+/// Identifies the frost library serialization of the enveloped key package
+/// Bump when upgrading to a frost release whose `KeyPackage` encoding changes,
+/// so older envelopes fail to load instead of mis-deserializing
+pub const FROST_KEY_FORMAT: &str = "frost-ed25519/2";
+
+/// Number of checksum bytes appended to the envelope
+const CHECKSUM_LEN: usize = 4;
+
+/// A versioned, checksummed envelope holding one participant's key package
+///
+/// Layout: `MAGIC || VERSION || u16 format_len || frost_format ||
+/// u16 name_len || name || u16 kp_len || key_package || checksum`, where the
+/// checksum is the first four bytes of the SHA-256 of everything preceding it
+/// and `frost_format` is `FROST_KEY_FORMAT` when sealed. Version 1 envelopes
+/// have no format field. The contained bytes are secret and are zeroized on
+/// drop.
+#[derive(Clone)]
+pub struct ShareEnvelope {
+    data: Vec<u8>,
+}
+
+impl ShareEnvelope {
+    /// Seal a participant's key package into an envelope
+    pub(crate) fn seal(
+        participant_name: &str,
+        key_package: &KeyPackage,
+    ) -> Result<Self> {
+        let kp_bytes = Zeroizing::new(key_package.serialize()?);
+        let name_bytes = participant_name.as_bytes();
+        if name_bytes.len() > u16::MAX as usize {
+            bail!("participant name too long for share envelope");
+        }
+
+        let format_bytes = FROST_KEY_FORMAT.as_bytes();
+        let mut data = Vec::with_capacity(
+            MAGIC.len()
+                + 7
+                + format_bytes.len()
+                + name_bytes.len()
+                + kp_bytes.len()
+                + CHECKSUM_LEN,
+        );
+        data.extend_from_slice(MAGIC);
+        data.push(VERSION);
+        data.extend_from_slice(&(format_bytes.len() as u16).to_be_bytes());
+        data.extend_from_slice(format_bytes);
+        data.extend_from_slice(&(name_bytes.len() as u16).to_be_bytes());
+        data.extend_from_slice(name_bytes);
+        data.extend_from_slice(&(kp_bytes.len() as u16).to_be_bytes());
+        data.extend_from_slice(&kp_bytes);
+        let checksum = sha256(&data);
+        data.extend_from_slice(&checksum[..CHECKSUM_LEN]);
+
+        Ok(Self { data })
+    }
+
+    /// Parse an envelope from bytes, validating its header and checksum
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let envelope = Self { data: bytes.to_vec() };
+        envelope.open()?;
+        Ok(envelope)
+    }
+
+    /// Get the raw envelope bytes (secret material)
+    pub fn as_bytes(&self) -> &[u8] { &self.data }
+
+    /// Get the name of the participant whose share this envelope holds
+    pub fn participant_name(&self) -> Result<String> {
+        Ok(self.open()?.0)
+    }
+
+    /// Decode the participant name and key package from the envelope
+    pub(crate) fn open(&self) -> Result<(String, KeyPackage)> {
+        let data = &self.data;
+        if data.len() < MAGIC.len() + 1 + CHECKSUM_LEN {
+            bail!("share envelope too short");
+        }
+        let (body, checksum) = data.split_at(data.len() - CHECKSUM_LEN);
+        if sha256(body)[..CHECKSUM_LEN] != *checksum {
+            bail!("share envelope checksum mismatch");
+        }
+        if &body[..MAGIC.len()] != MAGIC {
+            bail!("not a share envelope");
+        }
+        let version = body[MAGIC.len()];
+        let mut rest = &body[MAGIC.len() + 1..];
+        match version {
+            VERSION => {
+                let format = Self::read_field(&mut rest)?;
+                if format != FROST_KEY_FORMAT.as_bytes() {
+                    bail!(FrostError::UnsupportedKeyVersion {
+                        found: String::from_utf8_lossy(format).into_owned(),
+                        expected: FROST_KEY_FORMAT.to_string(),
+                    });
+                }
+            }
+            VERSION_UNTAGGED => {}
+            _ => bail!("unsupported share envelope version {}", version),
+        }
+
+        let name_bytes = Self::read_field(&mut rest)?;
+        let name = String::from_utf8(name_bytes.to_vec())?;
+        let kp_bytes = Self::read_field(&mut rest)?;
+        if !rest.is_empty() {
+            bail!("trailing bytes in share envelope");
+        }
+        let key_package = KeyPackage::deserialize(kp_bytes)?;
+
+        Ok((name, key_package))
+    }
+
+    /// Read a u16 length-prefixed field, advancing `rest` past it
+    fn read_field<'a>(rest: &mut &'a [u8]) -> Result<&'a [u8]> {
+        if rest.len() < 2 {
+            bail!("truncated share envelope");
+        }
+        let len = u16::from_be_bytes([rest[0], rest[1]]) as usize;
+        if rest.len() < 2 + len {
+            bail!("truncated share envelope");
+        }
+        let field = &rest[2..2 + len];
+        *rest = &rest[2 + len..];
+        Ok(field)
+    }
+}
+
+impl std::fmt::Debug for ShareEnvelope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShareEnvelope")
+            .field("len", &self.data.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for ShareEnvelope {
+    fn drop(&mut self) { self.data.zeroize(); }
+}
@@ -0,0 +1,52 @@
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{Result, anyhow};
+use provenance_mark::ProvenanceMark;
+
+use crate::FrostPmChain;
+
+/// A `FrostPmChain` that several threads can append to
+///
+/// Appends are inherently sequential: each mark's key is committed to by the
+/// previous tip, and its date must not precede the tip's. This wrapper
+/// therefore serializes appends rather than parallelizing them; each one runs
+/// against the current tip while holding the lock.
+#[derive(Debug)]
+pub struct SharedChain {
+    chain: Mutex<FrostPmChain>,
+}
+
+impl SharedChain {
+    /// Wrap a chain for shared use
+    pub fn new(chain: FrostPmChain) -> Self { Self { chain: Mutex::new(chain) } }
+
+    /// Run one append ceremony against the current tip
+    /// `ceremony` holds the lock for its whole duration, so it sees the tip
+    /// its mark will extend and can choose a date no earlier than the tip's
+    /// (e.g. `Date::now()` taken inside the closure). An error leaves the
+    /// chain unchanged.
+    pub fn append(
+        &self,
+        ceremony: impl FnOnce(&mut FrostPmChain) -> Result<ProvenanceMark>,
+    ) -> Result<ProvenanceMark> {
+        ceremony(&mut *self.lock()?)
+    }
+
+    /// Inspect the chain as of now, e.g. its tip or retained links
+    pub fn read<T>(&self, f: impl FnOnce(&FrostPmChain) -> T) -> Result<T> {
+        Ok(f(&*self.lock()?))
+    }
+
+    /// Unwrap the chain once it is no longer shared
+    pub fn into_inner(self) -> Result<FrostPmChain> {
+        self.chain
+            .into_inner()
+            .map_err(|_| anyhow!("shared chain lock poisoned"))
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, FrostPmChain>> {
+        self.chain
+            .lock()
+            .map_err(|_| anyhow!("shared chain lock poisoned"))
+    }
+}
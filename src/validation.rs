@@ -0,0 +1,57 @@
+/// One invariant `FrostPmChain::append_mark` enforces on a proposed mark
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalCheck {
+    /// The date is not earlier than the tip's
+    DateMonotonic,
+    /// The group's charter still matches the one bound at genesis
+    CharterUnchanged,
+    /// The commitments come from a threshold of known participants
+    CommitmentQuorum,
+    /// The commitments precommitted for the following mark do too
+    NextCommitmentQuorum,
+    /// The next commitments are not a replay of the current ones
+    FreshNextCommitments,
+    /// The key derived from the commitments is the one the tip committed to
+    KeyLinkage,
+    /// The signature verifies over the proposed mark's Round-2 message
+    Signature,
+}
+
+/// Outcome of every check run by `FrostPmChain::validate_proposed_mark`
+/// Unlike `append_mark`, which stops at the first failure, every check runs,
+/// so a rejected proposal can be diagnosed in one pass
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    results: Vec<(ProposalCheck, Result<(), String>)>,
+}
+
+impl ValidationReport {
+    /// Record the outcome of `check`
+    pub(crate) fn record(
+        &mut self,
+        check: ProposalCheck,
+        result: anyhow::Result<()>,
+    ) {
+        self.results.push((check, result.map_err(|e| e.to_string())));
+    }
+
+    /// Get each check with its outcome (the failure reason on error), in the
+    /// order they ran
+    pub fn results(&self) -> &[(ProposalCheck, Result<(), String>)] {
+        &self.results
+    }
+
+    /// Whether every check passed, so `append_mark` would accept the mark
+    pub fn is_valid(&self) -> bool {
+        self.results.iter().all(|(_, result)| result.is_ok())
+    }
+
+    /// Get the checks that failed, in the order they ran
+    pub fn failures(&self) -> Vec<ProposalCheck> {
+        self.results
+            .iter()
+            .filter(|(_, result)| result.is_err())
+            .map(|(check, _)| *check)
+            .collect()
+    }
+}
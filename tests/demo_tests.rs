@@ -0,0 +1,34 @@
+use anyhow::Result;
+use frost_pm_test::{
+    FrostGroup, FrostGroupConfig, demo::build_and_validate_chain,
+    rand_core::OsRng,
+};
+use provenance_mark::ProvenanceMarkResolution;
+
+#[test]
+fn test_build_and_validate_chain_report() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Demo report test".to_string(),
+    )?;
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    let report =
+        build_and_validate_chain(&group, ProvenanceMarkResolution::Low, 5)?;
+    assert_eq!(report.marks.len(), 5);
+    assert!(report.genesis_valid);
+    assert!(report.sequence_valid);
+    assert!(report.precedence_valid());
+    assert!(report.precedence_checks_total > 0);
+    assert!(report.resolution_consistent);
+    assert!(report.is_valid());
+    assert!(report.append_timings.total > report.append_timings.verify);
+
+    // Too short a chain is rejected
+    assert!(
+        build_and_validate_chain(&group, ProvenanceMarkResolution::Low, 1)
+            .is_err()
+    );
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anyhow::Result;
+use dcbor::Date;
+use frost_pm_test::{
+    FrostGroup, FrostGroupConfig, FrostPmChain, message::MessagePurpose,
+};
+use provenance_mark::{ProvenanceMark, ProvenanceMarkResolution};
+use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+
+const SEED: u64 = 0x5EED;
+
+const CHAIN_ID: &str = "9dd7c57e68eaa932c97998f9579ee41e";
+
+/// Hashes of marks 0 through 4; any protocol change that alters key
+/// derivation, message encoding, or signing shows up here
+const MARK_HASHES: [&str; 5] = [
+    "c7ff13c28a6144d58ee07efd4eb825c9",
+    "93b57afc64e4fc2625e03b4645f684ff",
+    "e2d4db57f51b7318ea8d31421bc3503e",
+    "3676c41307cf04ef0fe8212d2d160eb1",
+    "c256d2c2dbc83fbb06f980be196120db",
+];
+
+/// Build a five-mark chain (genesis plus four appends) drawing key
+/// generation and every Round-1 commitment from one RNG seeded with `seed`
+fn build_chain(seed: u64) -> Result<Vec<ProvenanceMark>> {
+    let mut rng = ChaCha20Rng::seed_from_u64(seed);
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Deterministic end-to-end chain".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Quartile;
+    let date_0 = Date::from_ymd(2025, 1, 1);
+    let info_0 = Some("genesis");
+    let message_0 = FrostPmChain::message_0(&config, res, date_0, info_0);
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut rng)?;
+
+    let signers = &["Alice", "Bob"];
+    let (commitments_0, nonces_0) = group.round_1_commit(signers, &mut rng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (mut commitments, mut nonces) =
+        group.round_1_commit(signers, &mut rng)?;
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0,
+        group,
+        signature_0,
+        &commitments,
+    )?;
+
+    let mut marks = vec![mark_0];
+    for seq in 1..=4 {
+        let date = Date::from_ymd(2025, 1, 1 + seq);
+        let info = Some(format!("mark {}", seq));
+        let message = chain.message_next(date, info.clone());
+        let signature = chain.group().round_2_sign_as(
+            MessagePurpose::Mark,
+            signers,
+            &commitments,
+            &nonces,
+            message.as_bytes(),
+        )?;
+        let (next_commitments, next_nonces) =
+            chain.group().round_1_commit(signers, &mut rng)?;
+        marks.push(chain.append_mark(
+            date,
+            info,
+            &commitments,
+            signature,
+            &next_commitments,
+        )?);
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+    Ok(marks)
+}
+
+#[test]
+fn test_seeded_chain_matches_known_hashes() -> Result<()> {
+    let marks = build_chain(SEED)?;
+    assert!(ProvenanceMark::is_sequence_valid(&marks));
+    for mark in &marks {
+        assert_eq!(hex::encode(mark.chain_id()), CHAIN_ID);
+    }
+    let hashes: Vec<_> = marks.iter().map(|m| hex::encode(m.hash())).collect();
+    assert_eq!(hashes, MARK_HASHES);
+    Ok(())
+}
+
+#[test]
+fn test_different_seed_changes_every_mark() -> Result<()> {
+    let marks = build_chain(SEED + 1)?;
+    assert_ne!(hex::encode(marks[0].chain_id()), CHAIN_ID);
+    for (mark, known) in marks.iter().zip(MARK_HASHES) {
+        assert_ne!(hex::encode(mark.hash()), known);
+    }
+    Ok(())
+}
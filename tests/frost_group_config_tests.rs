@@ -1,6 +1,10 @@
 use anyhow::Result;
 use frost_ed25519::{self as frost};
-use frost_pm_test::{FrostGroupConfig, rand_core::OsRng};
+use frost_pm_test::{
+    FrostError, FrostGroupConfig, NamePolicy, ReservedNames,
+    frost_group_config::DEFAULT_MAX_CHARTER_LEN, message::MessagePurpose,
+    rand_core::OsRng,
+};
 
 // Test helper functions
 fn corporate_board_config() -> Result<FrostGroupConfig> {
@@ -23,7 +27,7 @@ fn family_config() -> Result<FrostGroupConfig> {
 fn test_default_config() -> Result<()> {
     let config = FrostGroupConfig::new(
         2,
-        &["Alice", "Bob", "Eve"],
+        &["Alice", "Bob", "Carol"],
         "Default FROST group for testing".to_string(),
     )?;
     assert_eq!(config.min_signers(), 2);
@@ -31,7 +35,7 @@ fn test_default_config() -> Result<()> {
     assert_eq!(config.participant_ids().len(), 3);
 
     let names = config.participant_names_string();
-    assert_eq!(names, "Alice, Bob, Eve");
+    assert_eq!(names, "Alice, Bob, Carol");
     Ok(())
 }
 
@@ -117,7 +121,8 @@ fn test_genesis_message_integration_with_pm_chain() -> Result<()> {
     // Client generates genesis message and signs it
     let (commitments_0, nonces_0) =
         group.round_1_commit(&["Alice", "Bob"], &mut OsRng)?;
-    let signature_0 = group.round_2_sign(
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
         &["Alice", "Bob"],
         &commitments_0,
         &nonces_0,
@@ -130,7 +135,7 @@ fn test_genesis_message_integration_with_pm_chain() -> Result<()> {
 
     // Create a provenance mark chain - this now takes the pre-signed genesis
     // message and precommit data
-    let (_chain, genesis_mark) = FrostPmChain::new_chain(
+    let (_chain, genesis_mark, _) = FrostPmChain::new_chain(
         res,
         date_0,
         info_0,
@@ -140,7 +145,7 @@ fn test_genesis_message_integration_with_pm_chain() -> Result<()> {
     )?;
 
     // Test that the genesis message is accessible through the chain
-    let expected_genesis = "FROST Provenance Mark Chain\nResolution: medium, Threshold: 2 of 3\nParticipants: Alice, Bob, Charlie\nCharter: Test governance charter for integration test\nDate: 2025-01-01\nInfo Hash: e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+    let expected_genesis = "FROST Provenance Mark Chain\nResolution: medium, Threshold: 2 of 3\nParticipants: Alice, Bob, Charlie\nCharter: 44:Test governance charter for integration test\nDate: 1a67748580\nInfo Hash: e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
     assert_eq!(message_0, expected_genesis);
 
     // Verify the genesis mark was created successfully
@@ -152,11 +157,89 @@ fn test_genesis_message_integration_with_pm_chain() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_display_names() -> Result<()> {
+    use dcbor::Date;
+    use frost_pm_test::{FrostGroup, FrostPmChain};
+    use provenance_mark::ProvenanceMarkResolution;
+
+    let config = FrostGroupConfig::new(
+        2,
+        &["alice", "bob", "charlie"],
+        "Display name test".to_string(),
+    )?
+    .with_display_name("alice", "Dr. Alice Smith")?;
+    assert_eq!(config.participant_display_name("alice"), "Dr. Alice Smith");
+    assert_eq!(config.participant_display_name("bob"), "bob");
+    assert!(
+        config
+            .clone()
+            .with_display_name("dave", "Dave Jones")
+            .is_err()
+    );
+
+    // Signed material uses protocol names only
+    let res = ProvenanceMarkResolution::Medium;
+    let message_0 = FrostPmChain::message_0(
+        &config,
+        res,
+        Date::from_ymd(2025, 1, 1),
+        None::<String>,
+    );
+    assert!(message_0.contains("Participants: alice, bob, charlie\n"));
+    assert!(!message_0.contains("Dr. Alice Smith"));
+    let plain = FrostGroupConfig::new(
+        2,
+        &["alice", "bob", "charlie"],
+        "Display name test".to_string(),
+    )?;
+    assert_eq!(config.roster_hash()?, plain.roster_hash()?);
+
+    // Human-facing output shows the display name
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    assert_eq!(
+        group.public_summary().to_string(),
+        "2-of-3 group (Dr. Alice Smith, bob, charlie): Display name test"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_charter_digest_normalizes_whitespace() -> Result<()> {
+    let digest = |charter: &str| -> Result<[u8; 32]> {
+        let config = FrostGroupConfig::new(
+            2,
+            &["Alice", "Bob", "Charlie"],
+            charter.to_string(),
+        )?;
+        Ok(config.charter_digest())
+    };
+
+    // Line endings are unified
+    assert_eq!(digest("text\r\n")?, digest("text\n")?);
+    assert_eq!(digest("a\r\nb")?, digest("a\nb")?);
+    assert_eq!(digest("a\rb")?, digest("a\nb")?);
+
+    // Trailing whitespace is trimmed, per line and at the end
+    assert_eq!(digest("text")?, digest("text ")?);
+    assert_eq!(digest("text")?, digest("text\n\n")?);
+    assert_eq!(digest("a \t\nb")?, digest("a\nb")?);
+
+    // Anything else still matters
+    assert_ne!(digest("text")?, digest(" text")?);
+    assert_ne!(digest("a\nb")?, digest("a\n\nb")?);
+    assert_ne!(digest("a b")?, digest("a  b")?);
+    assert_eq!(digest("text")?, bc_crypto::sha256(b"text"));
+
+    Ok(())
+}
+
 #[test]
 fn test_participant_name_lookup() -> Result<()> {
     let config = FrostGroupConfig::new(
         2,
-        &["Alice", "Bob", "Eve"],
+        &["Alice", "Bob", "Carol"],
         "Default FROST group for testing".to_string(),
     )?;
     let participant_ids = config.participant_ids();
@@ -165,7 +248,7 @@ fn test_participant_name_lookup() -> Result<()> {
     for id in &participant_ids {
         let name = config.participant_name(id);
         assert_ne!(name, "Unknown");
-        assert!(["Alice", "Bob", "Eve"].contains(&name));
+        assert!(["Alice", "Bob", "Carol"].contains(&name));
     }
 
     // Test unknown identifier
@@ -178,11 +261,385 @@ fn test_participant_name_lookup() -> Result<()> {
 fn test_participant_names_string() -> Result<()> {
     let config = FrostGroupConfig::new(
         2,
-        &["Alice", "Bob", "Eve"],
+        &["Alice", "Bob", "Carol"],
         "Default FROST group for testing".to_string(),
     )?;
     let names = config.participant_names_string();
     // BTreeMap maintains sorted order, so we can predict the output
-    assert_eq!(names, "Alice, Bob, Eve");
+    assert_eq!(names, "Alice, Bob, Carol");
+    Ok(())
+}
+
+#[test]
+fn test_common_topology_constructors() -> Result<()> {
+    let config = FrostGroupConfig::two_of_three(
+        &["Alice", "Bob", "Charlie"],
+        "Two of three".to_string(),
+    )?;
+    assert_eq!(config.min_signers(), 2);
+    assert_eq!(config.max_signers(), 3);
+
+    let config = FrostGroupConfig::three_of_five(
+        &["CEO", "CFO", "CTO", "COO", "CLO"],
+        "Three of five".to_string(),
+    )?;
+    assert_eq!(config.min_signers(), 3);
+    assert_eq!(config.max_signers(), 5);
+
+    // Name counts must match the pattern
+    assert!(
+        FrostGroupConfig::two_of_three(
+            &["Alice", "Bob"],
+            "Too few".to_string()
+        )
+        .is_err()
+    );
+    assert!(
+        FrostGroupConfig::three_of_five(
+            &["CEO", "CFO", "CTO", "COO"],
+            "Too few".to_string()
+        )
+        .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_majority_of() -> Result<()> {
+    let config = FrostGroupConfig::majority_of(
+        &["CEO", "CFO", "CTO", "COO", "CLO"],
+        "Majority of five".to_string(),
+    )?;
+    assert_eq!(config.min_signers(), 3);
+    assert_eq!(config.max_signers(), 5);
+
+    let config = FrostGroupConfig::majority_of(
+        &["Alice", "Bob", "Charlie", "Diana"],
+        "Majority of four".to_string(),
+    )?;
+    assert_eq!(config.min_signers(), 3);
+
+    let config =
+        FrostGroupConfig::majority_of(&["Alice"], "Sole member".to_string())?;
+    assert_eq!(config.min_signers(), 1);
+
+    assert!(FrostGroupConfig::majority_of(&[], "Empty".to_string()).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_participant_names_in_id_order() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Zoe", "Anna", "Mike"],
+        "Identifier order test".to_string(),
+    )?;
+    assert_eq!(
+        config.participant_names_in_id_order(),
+        ["Zoe", "Anna", "Mike"]
+    );
+    assert_eq!(config.participant_names_string(), "Anna, Mike, Zoe");
+
+    // Identifier order matches share indices
+    let names = config.participant_names_in_id_order();
+    for (i, name) in names.iter().enumerate() {
+        let id = frost::Identifier::try_from((i + 1) as u16)?;
+        assert_eq!(config.participant_name(&id), name);
+    }
+    Ok(())
+}
+
+/// Example organizational policy: names must be non-empty single words
+struct NoWhitespace;
+
+impl NamePolicy for NoWhitespace {
+    fn validate(&self, name: &str) -> Result<()> {
+        if name.is_empty() || name.chars().any(char::is_whitespace) {
+            anyhow::bail!("invalid participant name: {:?}", name);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_name_policy() -> Result<()> {
+    let config = FrostGroupConfig::new_with_policy(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Policy test".to_string(),
+        &NoWhitespace,
+    )?;
+    assert_eq!(config.max_signers(), 3);
+
+    let result = FrostGroupConfig::new_with_policy(
+        2,
+        &["Alice", "Bob Smith", "Charlie"],
+        "Policy test".to_string(),
+        &NoWhitespace,
+    );
+    assert!(result.unwrap_err().to_string().contains("Bob Smith"));
+
+    // Closures work as policies too
+    let result = FrostGroupConfig::new_with_policy(
+        2,
+        &["alice", "Bob"],
+        "Policy test".to_string(),
+        &|name: &str| {
+            if name.starts_with(char::is_lowercase) {
+                anyhow::bail!("names must be capitalized");
+            }
+            Ok(())
+        },
+    );
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_reserved_names_policy() -> Result<()> {
+    let result = FrostGroupConfig::new_with_policy(
+        2,
+        &["Alice", "Bob", "Eve"],
+        "Policy test".to_string(),
+        &ReservedNames,
+    );
+    assert!(result.unwrap_err().to_string().contains("reserved"));
+
+    assert!(
+        FrostGroupConfig::new_with_policy(
+            2,
+            &["Alice", "Bob", "Charlie"],
+            "Policy test".to_string(),
+            &ReservedNames,
+        )
+        .is_ok()
+    );
+
+    // Plain `new` applies no policy
+    assert!(
+        FrostGroupConfig::new(2, &["Alice", "Eve"], "No policy".to_string())
+            .is_ok()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_participant_count_identifier_bounds() -> Result<()> {
+    // Distinct synthetic names up to one past the u16 identifier range
+    let names: Vec<&'static str> = (0..=u16::MAX as usize)
+        .map(|i| &*Box::leak(format!("P{}", i).into_boxed_str()))
+        .collect();
+
+    // Exactly u16::MAX participants fit, each with a unique identifier
+    let config = FrostGroupConfig::new(
+        2,
+        &names[..u16::MAX as usize],
+        "Boundary".to_string(),
+    )?;
+    assert_eq!(config.max_signers(), u16::MAX as usize);
+    let last_id = frost::Identifier::try_from(u16::MAX)?;
+    assert_eq!(config.participant_name(&last_id), "P65534");
+
+    // One more is a clear error rather than a wrapped identifier
+    let result = FrostGroupConfig::new(2, &names, "Overflow".to_string());
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("too many participants")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_charter_length_limit() -> Result<()> {
+    let names = &["Alice", "Bob"];
+    let too_long = "x".repeat(DEFAULT_MAX_CHARTER_LEN + 1);
+    let error = FrostGroupConfig::new(2, names, too_long).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::CharterTooLong {
+            max: DEFAULT_MAX_CHARTER_LEN,
+            got: DEFAULT_MAX_CHARTER_LEN + 1,
+        })
+    );
+
+    // A custom limit applies to the current charter and to replacements
+    let config = FrostGroupConfig::new(2, names, "Short".to_string())?
+        .with_max_charter_len(16)?;
+    assert_eq!(config.max_charter_len(), 16);
+    let replace = |charter: &str| {
+        config.clone().reconfigure().charter(charter.to_string()).build()
+    };
+    let replaced = replace("Exactly sixteen!")?;
+    assert_eq!(replaced.charter(), "Exactly sixteen!");
+    assert!(replace("Seventeen bytes!!").is_err());
+    assert!(replaced.with_max_charter_len(8).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_charter_is_length_delimited_in_genesis_message() -> Result<()> {
+    use dcbor::Date;
+    use frost_pm_test::FrostPmChain;
+    use provenance_mark::ProvenanceMarkResolution;
+
+    // A charter that mimics the structural fields around it
+    let charter = "Line one\nParticipants: Mallory\nDate: 1999-01-01";
+    let config =
+        FrostGroupConfig::new(2, &["Alice", "Bob"], charter.to_string())?;
+    let message = FrostPmChain::message_0(
+        &config,
+        ProvenanceMarkResolution::Low,
+        Date::from_ymd(2025, 1, 1),
+        None::<String>,
+    );
+
+    // Parse fields in order, reading the charter by its length prefix
+    let (head, rest) = message.split_once("\nCharter: ").unwrap();
+    let roster: Vec<&str> = head
+        .lines()
+        .filter_map(|l| l.strip_prefix("Participants: "))
+        .collect();
+    assert_eq!(roster, ["Alice, Bob"]);
+    let (len, rest) = rest.split_once(':').unwrap();
+    let (parsed_charter, rest) = rest.split_at(len.parse()?);
+    assert_eq!(parsed_charter, charter);
+    assert!(rest.starts_with("\nDate: 1a67748580\n"));
+    Ok(())
+}
+
+#[test]
+fn test_with_identifiers() -> Result<()> {
+    use frost_pm_test::FrostGroup;
+
+    let config = FrostGroupConfig::with_identifiers(
+        2,
+        &[("Alice", 10), ("Bob", 20), ("Charlie", 30)],
+        "Pinned identifiers".to_string(),
+    )?;
+    let id = |raw: u16| frost::Identifier::try_from(raw);
+    assert_eq!(config.participant_ids(), [id(10)?, id(20)?, id(30)?]);
+    assert_eq!(config.participant_name(&id(20)?), "Bob");
+
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    assert_eq!(group.key_package("Charlie")?.identifier(), &id(30)?);
+    let message = b"Signed with pinned identifiers";
+    let (commitments, nonces) =
+        group.round_1_commit(&["Alice", "Charlie"], &mut OsRng)?;
+    let signature = group.round_2_sign(
+        &["Alice", "Charlie"],
+        &commitments,
+        &nonces,
+        message,
+    )?;
+    assert!(group.verify(message, &signature).is_ok());
+
+    // Zero and duplicate identifiers are rejected
+    let charter = || "Invalid".to_string();
+    assert!(
+        FrostGroupConfig::with_identifiers(1, &[("Alice", 0)], charter())
+            .is_err()
+    );
+    assert!(
+        FrostGroupConfig::with_identifiers(
+            1,
+            &[("Alice", 7), ("Bob", 7)],
+            charter()
+        )
+        .is_err()
+    );
+    assert!(
+        FrostGroupConfig::with_identifiers(
+            1,
+            &[("Alice", 7), ("Alice", 8)],
+            charter()
+        )
+        .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_from_lines_roster() -> Result<()> {
+    let roster = concat!(
+        "# Board of directors\n",
+        "Alice\n",
+        "\n",
+        "  Bob  \n",
+        "# on leave until Q3\n",
+        "Charlie\n",
+        "\t\n",
+    );
+    let config =
+        FrostGroupConfig::from_lines(roster.as_bytes(), 2, "Board".into())?;
+    assert_eq!(config.max_signers(), 3);
+    assert_eq!(config.min_signers(), 2);
+    assert_eq!(
+        config.participant_names_in_id_order(),
+        vec!["Alice", "Bob", "Charlie"]
+    );
+
+    // Same roster from a file
+    let path = std::env::temp_dir().join("frost-pm-roster-test.txt");
+    std::fs::write(&path, roster)?;
+    let from_file =
+        FrostGroupConfig::from_participants_file(&path, 2, "Board".into());
+    std::fs::remove_file(&path)?;
+    assert_eq!(
+        from_file?.participant_names_in_id_order(),
+        config.participant_names_in_id_order()
+    );
+
+    // Duplicate names and unreachable thresholds are rejected
+    let duplicated = "Alice\nBob\nAlice\n";
+    assert!(
+        FrostGroupConfig::from_lines(duplicated.as_bytes(), 2, "x".into())
+            .is_err()
+    );
+    assert!(
+        FrostGroupConfig::from_lines(roster.as_bytes(), 4, "x".into())
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_reserve_identifier_gaps() -> Result<()> {
+    let mut config = FrostGroupConfig::reserve_identifier_gaps(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        5,
+        "Growing board".to_string(),
+    )?;
+    assert_eq!(config.max_signers(), 3);
+    assert_eq!(
+        config.reserved_slots(),
+        [frost::Identifier::try_from(4)?, frost::Identifier::try_from(5)?]
+    );
+
+    let diana = config.add_participant("Diana")?;
+    assert_eq!(diana, frost::Identifier::try_from(4)?);
+    assert_eq!(config.participant_name(&diana), "Diana");
+    assert_eq!(config.max_signers(), 4);
+    assert_eq!(
+        config.participant_names_in_id_order(),
+        vec!["Alice", "Bob", "Charlie", "Diana"]
+    );
+
+    // Names stay unique, and slots run out
+    assert!(config.add_participant("Alice").is_err());
+    config.add_participant("Eliza")?;
+    assert!(config.add_participant("Frank").is_err());
+
+    assert!(
+        FrostGroupConfig::reserve_identifier_gaps(
+            2,
+            &["Alice", "Bob", "Charlie"],
+            2,
+            "Too few slots".to_string(),
+        )
+        .is_err()
+    );
     Ok(())
 }
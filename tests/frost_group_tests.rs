@@ -1,5 +1,24 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
 use anyhow::Result;
-use frost_pm_test::{FrostGroup, FrostGroupConfig, rand_core::OsRng};
+use frost_ed25519::{
+    SigningPackage,
+    keys::{KeyPackage, PublicKeyPackage},
+};
+use frost_pm_test::{
+    CancellationToken, FrostError, FrostGroup, FrostGroupConfig,
+    GroupAttestation, GroupWarning, PublicGroupSummary, ShareEnvelope,
+    frost_group::PREHASH_RECOMMENDED_LEN,
+    message::{MessagePurpose, aad_message, prehash},
+    public_summary::verify_attestation,
+    rand_core::{CryptoRng, OsRng, RngCore},
+    share_envelope::FROST_KEY_FORMAT,
+};
+use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
 
 // Test helper functions
 pub fn corporate_board_config() -> FrostGroupConfig {
@@ -24,7 +43,7 @@ pub fn family_config() -> FrostGroupConfig {
 fn test_group_creation_with_trusted_dealer() -> Result<()> {
     let config = FrostGroupConfig::new(
         2,
-        &["Alice", "Bob", "Eve"],
+        &["Alice", "Bob", "Carol"],
         "Default FROST group for testing".to_string(),
     )?;
     let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
@@ -32,7 +51,7 @@ fn test_group_creation_with_trusted_dealer() -> Result<()> {
     assert_eq!(group.min_signers(), 2);
     assert_eq!(group.max_signers(), 3);
     assert_eq!(group.participant_names().len(), 3);
-    assert_eq!(group.participant_names().join(", "), "Alice, Bob, Eve");
+    assert_eq!(group.participant_names().join(", "), "Alice, Bob, Carol");
 
     // Verify all participants have key packages
     for participant_name in group.participant_names() {
@@ -45,7 +64,7 @@ fn test_group_creation_with_trusted_dealer() -> Result<()> {
 fn test_group_signing() -> Result<()> {
     let config = FrostGroupConfig::new(
         2,
-        &["Alice", "Bob", "Eve"],
+        &["Alice", "Bob", "Carol"],
         "Default FROST group for testing".to_string(),
     )?;
     let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
@@ -83,7 +102,7 @@ fn test_group_signing() -> Result<()> {
 fn test_group_insufficient_signers() -> Result<()> {
     let config = FrostGroupConfig::new(
         2,
-        &["Alice", "Bob", "Eve"],
+        &["Alice", "Bob", "Carol"],
         "Default FROST group for testing".to_string(),
     )?;
     let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
@@ -114,7 +133,7 @@ fn test_corporate_board_signing() -> Result<()> {
     let participant_names = group.participant_names();
     let signers: Vec<&str> = participant_names
         .iter()
-        .take(group.min_signers() as usize)
+        .take(group.min_signers())
         .map(|s| s.as_str())
         .collect();
     assert_eq!(signers.len(), 3);
@@ -126,11 +145,31 @@ fn test_corporate_board_signing() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_group_participant_name_orderings() -> Result<()> {
+    let config = corporate_board_config();
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    // Lexicographic
+    assert_eq!(group.participant_names(), ["CEO", "CFO", "CLO", "COO", "CTO"]);
+
+    // Identifier (config) order, matching the key package identifiers
+    let by_id = group.participant_names_by_id();
+    assert_eq!(by_id, ["CEO", "CFO", "CTO", "COO", "CLO"]);
+    for (i, name) in by_id.iter().enumerate() {
+        assert_eq!(
+            group.key_package(name)?.identifier(),
+            &frost_ed25519::Identifier::try_from(i as u16 + 1)?
+        );
+    }
+    Ok(())
+}
+
 #[test]
 fn test_group_participant_management() -> Result<()> {
     let config = FrostGroupConfig::new(
         2,
-        &["Alice", "Bob", "Eve"],
+        &["Alice", "Bob", "Carol"],
         "Default FROST group for testing".to_string(),
     )?;
     let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
@@ -141,13 +180,13 @@ fn test_group_participant_management() -> Result<()> {
 
     // Test that all names are valid
     for name in &participant_names {
-        assert!(["Alice", "Bob", "Eve"].contains(&name.as_str()));
+        assert!(["Alice", "Bob", "Carol"].contains(&name.as_str()));
         assert!(group.has_participant(name));
     }
 
     // Test participant names string
     let names_string = group.participant_names().join(", ");
-    assert_eq!(names_string, "Alice, Bob, Eve");
+    assert_eq!(names_string, "Alice, Bob, Carol");
     Ok(())
 }
 
@@ -156,7 +195,7 @@ fn test_group_basic_functionality() -> Result<()> {
     // Test that demonstrates the basic functionality works
     let config = FrostGroupConfig::new(
         2,
-        &["Alice", "Bob", "Eve"],
+        &["Alice", "Bob", "Carol"],
         "Default FROST group for testing".to_string(),
     )?;
     let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
@@ -187,3 +226,1537 @@ fn test_group_basic_functionality() -> Result<()> {
     assert!(group.verify(wrong_message, &signature).is_err());
     Ok(())
 }
+
+#[test]
+fn test_share_export_import_distributed_signing() -> Result<()> {
+    let config = family_config();
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    // Export each share and hand it to its holder as raw bytes
+    let mut holders = BTreeMap::new();
+    for name in group.participant_names() {
+        let envelope = group.export_share(&name)?;
+        assert_eq!(envelope.participant_name()?, name);
+        let bytes = envelope.as_bytes().to_vec();
+        let received = ShareEnvelope::from_bytes(&bytes)?;
+        let holder = FrostGroup::import_share(
+            group.config().clone(),
+            group.public_key_package().clone(),
+            &received,
+        )?;
+        assert_eq!(holder.key_package(&name)?, group.key_package(&name)?);
+        holders.insert(name, holder);
+    }
+
+    // Each holder only has its own share
+    assert!(holders["Alice"].key_package("Bob").is_err());
+
+    // Distributed signing: each holder runs its own rounds
+    let message = b"Distributed signing with imported shares";
+    let signers = ["Alice", "Diana"];
+    let mut commitments = BTreeMap::new();
+    let mut nonces = BTreeMap::new();
+    for name in signers {
+        let holder = &holders[name];
+        let (n, c) = holder.commit_for_participant(name, &mut OsRng)?;
+        commitments.insert(holder.name_to_id(name)?, c);
+        nonces.insert(name, n);
+    }
+    let signing_package = FrostGroup::signing_package(
+        MessagePurpose::Application,
+        &commitments,
+        message,
+    );
+    let mut shares = BTreeMap::new();
+    for name in signers {
+        let holder = &holders[name];
+        let share = holder.sign_for_participant(
+            name,
+            &signing_package,
+            &nonces[name],
+        )?;
+        shares.insert(holder.name_to_id(name)?, share);
+    }
+    let signature = group.aggregate(&signing_package, &shares)?;
+    assert!(group.verify(message, &signature).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn test_share_envelope_rejects_tampering() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let envelope = group.export_share("Bob")?;
+
+    // Flipping any byte breaks the checksum
+    let mut bytes = envelope.as_bytes().to_vec();
+    bytes[10] ^= 0x01;
+    assert!(ShareEnvelope::from_bytes(&bytes).is_err());
+
+    // Truncated envelopes are rejected
+    let bytes = envelope.as_bytes();
+    assert!(ShareEnvelope::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    // A share cannot be imported into a different group
+    let other =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let result = FrostGroup::import_share(
+        other.config().clone(),
+        other.public_key_package().clone(),
+        &envelope,
+    );
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+/// Rebuild an envelope with a different version byte and frost format field
+/// (`None` omits the field), recomputing the checksum
+fn reseal_envelope(
+    envelope: &ShareEnvelope,
+    version: u8,
+    format: Option<&str>,
+) -> Vec<u8> {
+    let bytes = envelope.as_bytes();
+    let body = &bytes[..bytes.len() - 4];
+    let format_len = u16::from_be_bytes([body[5], body[6]]) as usize;
+    let mut data = b"FPMS".to_vec();
+    data.push(version);
+    if let Some(format) = format {
+        data.extend_from_slice(&(format.len() as u16).to_be_bytes());
+        data.extend_from_slice(format.as_bytes());
+    }
+    data.extend_from_slice(&body[7 + format_len..]);
+    let checksum = bc_crypto::sha256(&data);
+    data.extend_from_slice(&checksum[..4]);
+    data
+}
+
+#[test]
+fn test_share_envelope_frost_version() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let envelope = group.export_share("Diana")?;
+
+    // A bogus frost version fails cleanly before reaching frost
+    let bogus = reseal_envelope(&envelope, 2, Some("frost-ed25519/99"));
+    let error = ShareEnvelope::from_bytes(&bogus).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::UnsupportedKeyVersion {
+            found: "frost-ed25519/99".to_string(),
+            expected: FROST_KEY_FORMAT.to_string(),
+        })
+    );
+
+    // Untagged version 1 envelopes still load
+    let v1 = reseal_envelope(&envelope, 1, None);
+    let restored = ShareEnvelope::from_bytes(&v1)?;
+    assert_eq!(restored.participant_name()?, "Diana");
+    FrostGroup::import_share(
+        group.config().clone(),
+        group.public_key_package().clone(),
+        &restored,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_with_identifiable_abort() -> Result<()> {
+    let group = FrostGroup::new_with_trusted_dealer(
+        corporate_board_config(),
+        &mut OsRng,
+    )?;
+    let message = b"Board resolution with faulty signers";
+    let signers = ["CEO", "CFO", "CTO"];
+
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+    let signing_package = FrostGroup::signing_package(
+        MessagePurpose::Application,
+        &commitments,
+        message,
+    );
+    let mut shares = BTreeMap::new();
+    for name in signers {
+        let share =
+            group.sign_for_participant(name, &signing_package, &nonces[name])?;
+        shares.insert(group.name_to_id(name)?, share);
+    }
+
+    // Honest shares aggregate normally
+    let signature =
+        group.aggregate_with_identifiable_abort(&signing_package, &shares)?;
+    assert!(group.verify(message, &signature).is_ok());
+
+    // Two signers submit shares over a different message
+    let other_package = FrostGroup::signing_package(
+        MessagePurpose::Application,
+        signing_package.signing_commitments(),
+        b"A different resolution",
+    );
+    for name in ["CEO", "CTO"] {
+        let share =
+            group.sign_for_participant(name, &other_package, &nonces[name])?;
+        shares.insert(group.name_to_id(name)?, share);
+    }
+
+    let error = group
+        .aggregate_with_identifiable_abort(&signing_package, &shares)
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::InvalidSignatureShares {
+            culprits: vec!["CEO".to_string(), "CTO".to_string()],
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_round_2_sign_subset_tolerates_dropout() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let message = b"Signed by whoever is still around";
+
+    // Three participants commit in Round-1
+    let (commitments, mut nonces) =
+        group.round_1_commit(&["Alice", "Bob", "Charlie"], &mut OsRng)?;
+    assert_eq!(commitments.len(), 3);
+
+    // Bob drops out before Round-2 and his nonces are gone
+    nonces.remove("Bob");
+    let signature = group.round_2_sign_subset(
+        &["Bob", "Charlie", "Alice"],
+        &commitments,
+        &nonces,
+        message,
+    )?;
+    assert!(group.verify(message, &signature).is_ok());
+
+    // Not enough remaining participants fails
+    nonces.remove("Alice");
+    let result = group.round_2_sign_subset(
+        &["Alice", "Bob", "Charlie"],
+        &commitments,
+        &nonces,
+        message,
+    );
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_verify_share_against_message() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let message = b"Gate shares before aggregation";
+    let app = MessagePurpose::Application;
+    let signers = ["Bob", "Diana"];
+
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+    let signing_package = FrostGroup::signing_package(
+        MessagePurpose::Application,
+        &commitments,
+        message,
+    );
+    let bob_share =
+        group.sign_for_participant("Bob", &signing_package, &nonces["Bob"])?;
+    let diana_share = group.sign_for_participant(
+        "Diana",
+        &signing_package,
+        &nonces["Diana"],
+    )?;
+
+    // Good shares pass
+    group.verify_share_against_message(
+        app,
+        "Bob",
+        &commitments,
+        message,
+        &bob_share,
+    )?;
+    group.verify_share_against_message(
+        app,
+        "Diana",
+        &commitments,
+        message,
+        &diana_share,
+    )?;
+
+    // A share attributed to the wrong participant fails, naming them
+    let error = group
+        .verify_share_against_message(
+            app,
+            "Diana",
+            &commitments,
+            message,
+            &bob_share,
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("Diana"));
+
+    // A share over a different message fails
+    let other_package = FrostGroup::signing_package(
+        MessagePurpose::Application,
+        &commitments,
+        b"Other",
+    );
+    let tampered =
+        group.sign_for_participant("Bob", &other_package, &nonces["Bob"])?;
+    let error = group
+        .verify_share_against_message(
+            app,
+            "Bob",
+            &commitments,
+            message,
+            &tampered,
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("Bob"));
+
+    // Mark shares are gated under their own purpose, and a share made for
+    // one purpose fails under another
+    let mark_package = FrostGroup::signing_package(
+        MessagePurpose::Mark,
+        &commitments,
+        message,
+    );
+    let mark_share =
+        group.sign_for_participant("Bob", &mark_package, &nonces["Bob"])?;
+    group.verify_share_against_message(
+        MessagePurpose::Mark,
+        "Bob",
+        &commitments,
+        message,
+        &mark_share,
+    )?;
+    assert!(
+        group
+            .verify_share_against_message(
+                app,
+                "Bob",
+                &commitments,
+                message,
+                &mark_share,
+            )
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_verify_shares_batch() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let signers = ["Bob", "Diana"];
+    let bob = group.name_to_id("Bob")?;
+    let diana = group.name_to_id("Diana")?;
+
+    // Two published ceremonies, each with both signers' shares
+    let mut transcripts = Vec::new();
+    for message in [b"Mark one".as_slice(), b"Mark two"] {
+        let (commitments, nonces) =
+            group.round_1_commit(&signers, &mut OsRng)?;
+        let package = FrostGroup::signing_package(
+            MessagePurpose::Mark,
+            &commitments,
+            message,
+        );
+        let bob_share =
+            group.sign_for_participant("Bob", &package, &nonces["Bob"])?;
+        let diana_share =
+            group.sign_for_participant("Diana", &package, &nonces["Diana"])?;
+        transcripts.push((package, bob_share, diana_share));
+    }
+    let [(package_1, bob_1, diana_1), (package_2, bob_2, _)] =
+        &transcripts[..]
+    else {
+        unreachable!()
+    };
+
+    // Diana's entry for the second mark carries her share from the first
+    let results = group.verify_shares_batch(&[
+        (bob, package_1, bob_1),
+        (diana, package_1, diana_1),
+        (bob, package_2, bob_2),
+        (diana, package_2, diana_1),
+    ]);
+    assert_eq!(
+        results,
+        [(bob, true), (diana, true), (bob, true), (diana, false)]
+    );
+    assert!(group.verify_shares_batch(&[]).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_group_cbor_round_trip() -> Result<()> {
+    let config = FrostGroupConfig::with_identifiers(
+        2,
+        &[("Alice", 9), ("Bob", 2), ("Charlie", 5)],
+        "Serialized group".to_string(),
+    )?;
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let signers = ["Alice", "Charlie"];
+    let message = b"Signed before the round trip";
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+    let signature =
+        group.round_2_sign(&signers, &commitments, &nonces, message)?;
+
+    // The full form restores a group that signs
+    let full = FrostGroup::from_cbor(group.to_cbor(true)?)?;
+    assert!(full.can_sign() && full.is_complete());
+    assert_eq!(full.verifying_key(), group.verifying_key());
+    assert_eq!(full.public_summary(), group.public_summary());
+    full.verify(message, &signature)?;
+    let (commitments, nonces) = full.round_1_commit(&signers, &mut OsRng)?;
+    let resigned =
+        full.round_2_sign(&signers, &commitments, &nonces, message)?;
+    group.verify(message, &resigned)?;
+
+    // The public form verifies but refuses to sign
+    let encoded = group.to_cbor(false)?;
+    let public = FrostGroup::from_cbor(encoded.clone())?;
+    assert!(!public.can_sign());
+    assert!(public.held_shares().is_empty());
+    assert_eq!(public.public_summary(), group.public_summary());
+    public.verify(message, &signature)?;
+    let error = public.round_1_commit(&signers, &mut OsRng).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::PublicOnlyGroup)
+    );
+
+    // The tag decides the form; a public blob cannot claim to be full
+    let mut relabeled = encoded.try_into_map()?;
+    relabeled.insert("form", "full");
+    assert!(FrostGroup::from_cbor(relabeled.into()).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_held_shares() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    assert_eq!(group.held_shares(), ["Alice", "Bob", "Charlie", "Diana"]);
+
+    let partial = FrostGroup::import_share(
+        group.config().clone(),
+        group.public_key_package().clone(),
+        &group.export_share("Charlie")?,
+    )?;
+    assert_eq!(partial.held_shares(), ["Charlie"]);
+    assert_eq!(partial.participant_names().len(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_missing_shares() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    assert!(group.is_complete());
+    assert!(group.missing_shares().is_empty());
+
+    let partial = FrostGroup::import_share(
+        group.config().clone(),
+        group.public_key_package().clone(),
+        &group.export_share("Charlie")?,
+    )?;
+    assert!(!partial.is_complete());
+    assert_eq!(partial.missing_shares(), ["Alice", "Bob", "Diana"]);
+
+    // The co-located path names every share it lacks
+    let error = partial
+        .round_1_commit(&["Alice", "Charlie", "Diana"], &mut OsRng)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "cannot sign locally, missing shares for Alice, Diana"
+    );
+
+    // Unknown names are still reported as such
+    let error = partial
+        .round_1_commit(&["Charlie", "Mallory"], &mut OsRng)
+        .unwrap_err();
+    assert_eq!(error.to_string(), "Unknown participant: Mallory");
+
+    Ok(())
+}
+
+#[test]
+fn test_ceremony_aborted_report() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Abort report test".to_string(),
+    )?;
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    // Only Alice responds to Round-1 before the deadline
+    let (alice_nonces, alice_commitments) =
+        group.commit_for_participant("Alice", &mut OsRng)?;
+    let alice_id = group.name_to_id("Alice")?;
+    let commitments = BTreeMap::from([(alice_id, alice_commitments)]);
+    let nonces = BTreeMap::from([("Alice".to_string(), alice_nonces)]);
+
+    let error = group
+        .round_2_sign_subset(
+            &["Alice", "Bob", "Charlie"],
+            &commitments,
+            &nonces,
+            b"Never signed",
+        )
+        .unwrap_err();
+    match error.downcast_ref::<FrostError>() {
+        Some(FrostError::CeremonyAborted { committed, signed, reason }) => {
+            assert_eq!(committed, &[alice_id]);
+            assert_eq!(signed, &[alice_id]);
+            assert!(reason.contains("need 2"));
+        }
+        other => panic!("expected CeremonyAborted, got {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_trusted_dealer_distributed_sink() -> Result<()> {
+    let config = corporate_board_config();
+
+    // Each share goes straight to its holder's process
+    let mut key_packages = Vec::new();
+    let public_key_package = FrostGroup::new_with_trusted_dealer_distributed(
+        &config,
+        &mut OsRng,
+        |id, key_package| key_packages.push((id, key_package)),
+    )?;
+    assert_eq!(key_packages.len(), 5);
+
+    let mut holders = BTreeMap::new();
+    for (id, key_package) in key_packages {
+        let holder = FrostGroup::new_for_participant(
+            config.clone(),
+            public_key_package.clone(),
+            key_package,
+        )?;
+        let name = config.participant_name(&id);
+        assert_eq!(holder.held_shares(), [name]);
+        holders.insert(name.to_string(), holder);
+    }
+
+    // Distributed signing across three separate holders
+    let message = b"Distributed dealer resolution";
+    let signers = ["CFO", "COO", "CLO"];
+    let mut commitments = BTreeMap::new();
+    let mut nonces = BTreeMap::new();
+    for name in signers {
+        let (n, c) = holders[name].commit_for_participant(name, &mut OsRng)?;
+        commitments.insert(holders[name].name_to_id(name)?, c);
+        nonces.insert(name, n);
+    }
+    let signing_package = FrostGroup::signing_package(
+        MessagePurpose::Application,
+        &commitments,
+        message,
+    );
+    let mut shares = BTreeMap::new();
+    for name in signers {
+        let share = holders[name].sign_for_participant(
+            name,
+            &signing_package,
+            &nonces[name],
+        )?;
+        shares.insert(holders[name].name_to_id(name)?, share);
+    }
+    let coordinator = &holders["CEO"];
+    let signature = coordinator.aggregate(&signing_package, &shares)?;
+    assert!(coordinator.verify(message, &signature).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn test_sign_with_commitments() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let message = b"Logged release";
+    let signers = ["Bob", "Diana"];
+    let (signature, commitments) =
+        group.sign_with_commitments(message, &signers, &mut OsRng)?;
+    assert!(group.verify(message, &signature).is_ok());
+
+    // The published commitments name exactly the signers
+    let bob = group.name_to_id("Bob")?;
+    let diana = group.name_to_id("Diana")?;
+    assert!(commitments.keys().eq([bob, diana].iter()));
+
+    // A log reader can check the ceremony against the commitments
+    let summary = group.public_summary();
+    let package = |commitments| {
+        FrostGroup::signing_package(
+            MessagePurpose::Application,
+            &commitments,
+            message,
+        )
+    };
+    summary.verify_with_package(&package(commitments.clone()), &signature)?;
+
+    // Swapping the signers' commitments, or publishing another quorum's,
+    // does not match the signature
+    let mut swapped = commitments.clone();
+    swapped.insert(bob, commitments[&diana]);
+    swapped.insert(diana, commitments[&bob]);
+    let (forged, _) = group.round_1_commit(&["Alice", "Charlie"], &mut OsRng)?;
+    for published in [swapped, forged] {
+        let error = summary
+            .verify_with_package(&package(published), &signature)
+            .unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<FrostError>(),
+            Some(&FrostError::UnboundCommitments)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_participants_meeting_weight() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["CEO", "Dev1", "Dev2", "Dev3"],
+        "Weighted quorum planning".to_string(),
+    )?
+    .with_participant_weight("CEO", 3)?;
+    assert_eq!(config.participant_weight("CEO"), 3);
+    assert_eq!(config.participant_weight("Dev1"), 1);
+    assert!(
+        config
+            .clone()
+            .with_participant_weight("Mallory", 2)
+            .is_err()
+    );
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    // The CEO's weight alone reaches the target, but FROST still needs the
+    // threshold's two signers; sets padding the CEO past it are not minimal
+    let sets = group.participants_meeting_weight(3);
+    assert_eq!(
+        sets,
+        [
+            vec!["CEO", "Dev1"],
+            vec!["CEO", "Dev2"],
+            vec!["CEO", "Dev3"],
+            vec!["Dev1", "Dev2", "Dev3"],
+        ]
+    );
+    assert!(sets.iter().all(|set| set.len() >= group.min_signers()));
+
+    let sets = group.participants_meeting_weight(4);
+    assert_eq!(
+        sets,
+        [vec!["CEO", "Dev1"], vec!["CEO", "Dev2"], vec!["CEO", "Dev3"]]
+    );
+
+    // Unreachable targets yield nothing
+    assert!(group.participants_meeting_weight(7).is_empty());
+    Ok(())
+}
+
+/// RNG wrapper that sleeps before every draw, simulating a blocking source
+struct SlowRng(Duration);
+
+impl RngCore for SlowRng {
+    fn next_u32(&mut self) -> u32 {
+        std::thread::sleep(self.0);
+        OsRng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        std::thread::sleep(self.0);
+        OsRng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        std::thread::sleep(self.0);
+        OsRng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(
+        &mut self,
+        dest: &mut [u8],
+    ) -> Result<(), frost_pm_test::rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for SlowRng {}
+
+/// `SlowRng` whose owner holds a clone of the `Arc`, so a test can tell
+/// when the worker thread that took it has exited
+struct TrackedRng {
+    rng: SlowRng,
+    _alive: Arc<()>,
+}
+
+impl RngCore for TrackedRng {
+    fn next_u32(&mut self) -> u32 { self.rng.next_u32() }
+
+    fn next_u64(&mut self) -> u64 { self.rng.next_u64() }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) { self.rng.fill_bytes(dest) }
+
+    fn try_fill_bytes(
+        &mut self,
+        dest: &mut [u8],
+    ) -> Result<(), frost_pm_test::rand_core::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl CryptoRng for TrackedRng {}
+
+/// RNG that only ever produces zeros, despite claiming `CryptoRng`
+struct ZeroRng;
+
+impl RngCore for ZeroRng {
+    fn next_u32(&mut self) -> u32 { 0 }
+
+    fn next_u64(&mut self) -> u64 { 0 }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) { dest.fill(0) }
+
+    fn try_fill_bytes(
+        &mut self,
+        dest: &mut [u8],
+    ) -> Result<(), frost_pm_test::rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for ZeroRng {}
+
+#[test]
+#[cfg(debug_assertions)]
+fn test_dealer_rejects_degenerate_rng() -> Result<()> {
+    let error =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut ZeroRng)
+            .unwrap_err();
+    assert_eq!(error.downcast_ref::<FrostError>(), Some(&FrostError::WeakRng));
+    let error = FrostGroup::new_with_trusted_dealer_distributed(
+        &family_config(),
+        &mut ZeroRng,
+        |_, _| {},
+    )
+    .unwrap_err();
+    assert_eq!(error.downcast_ref::<FrostError>(), Some(&FrostError::WeakRng));
+
+    // A real source passes the check
+    FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    Ok(())
+}
+
+#[test]
+fn test_sign_with_timeout() -> Result<()> {
+    let group = FrostGroup::new_with_trusted_dealer(
+        FrostGroupConfig::two_of_three(
+            &["Alice", "Bob", "Charlie"],
+            "Timeout test".to_string(),
+        )?,
+        &mut OsRng,
+    )?;
+    let message = b"Timed signing";
+    let signers = ["Alice", "Bob"];
+
+    // A fast RNG completes well within the limit
+    let signature = group.sign_with_timeout(
+        message,
+        &signers,
+        OsRng,
+        Duration::from_secs(10),
+    )?;
+    assert!(group.verify(message, &signature).is_ok());
+
+    // A blocking RNG trips the guard
+    let limit = Duration::from_millis(50);
+    let error = group
+        .sign_with_timeout(
+            message,
+            &signers,
+            SlowRng(Duration::from_millis(500)),
+            limit,
+        )
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::Timeout { limit })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_sign_cancellable() -> Result<()> {
+    let group = FrostGroup::new_with_trusted_dealer(
+        FrostGroupConfig::two_of_three(
+            &["Alice", "Bob", "Charlie"],
+            "Cancellation test".to_string(),
+        )?,
+        &mut OsRng,
+    )?;
+    let message = b"Cancellable signing";
+    let signers = ["Alice", "Bob"];
+
+    // An untouched token lets the ceremony finish
+    let token = CancellationToken::new();
+    let signature =
+        group.sign_cancellable(message, &signers, OsRng, &token)?;
+    assert!(group.verify(message, &signature).is_ok());
+
+    // Cancelling from another thread aborts a ceremony stuck in Round 1
+    let remote = token.clone();
+    let canceller = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        remote.cancel();
+    });
+    let error = group
+        .sign_cancellable(
+            message,
+            &signers,
+            SlowRng(Duration::from_millis(500)),
+            &token,
+        )
+        .unwrap_err();
+    canceller.join().unwrap();
+    assert!(token.is_cancelled());
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::Cancelled { committed: vec![] })
+    );
+
+    // An already-cancelled token fails without waiting
+    let error = group
+        .sign_cancellable(message, &signers, OsRng, &token)
+        .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<FrostError>(),
+        Some(FrostError::Cancelled { .. })
+    ));
+
+    // Once cancelled the worker drops its nonces rather than run Round 2,
+    // which would report a large message to the observer
+    let mut observed = group.clone();
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let sink = warnings.clone();
+    observed.set_observer(move |w| sink.lock().unwrap().push(w.clone()));
+    let large = vec![0u8; PREHASH_RECOMMENDED_LEN + 1];
+    let token = CancellationToken::new();
+    let remote = token.clone();
+    let canceller = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        remote.cancel();
+    });
+    let alive = Arc::new(());
+    let rng = TrackedRng {
+        rng: SlowRng(Duration::from_millis(100)),
+        _alive: alive.clone(),
+    };
+    assert!(observed.sign_cancellable(&large, &signers, rng, &token).is_err());
+    canceller.join().unwrap();
+    while Arc::strong_count(&alive) > 1 {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(warnings.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_key_material_threshold_mismatch_rejected() -> Result<()> {
+    // Key material dealt for 3-of-3 paired with a 2-of-3 config
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Mismatched threshold".to_string(),
+    )?;
+    let (secret_shares, public_key_package) =
+        frost_ed25519::keys::generate_with_dealer(
+            3,
+            3,
+            frost_ed25519::keys::IdentifierList::Custom(
+                &config.participant_ids(),
+            ),
+            OsRng,
+        )?;
+    let mut key_packages = BTreeMap::new();
+    for (id, share) in secret_shares {
+        key_packages.insert(id, KeyPackage::try_from(share)?);
+    }
+    let alice = key_packages[&config.participant_ids()[0]].clone();
+
+    let error = FrostGroup::new_from_key_material(
+        config.clone(),
+        key_packages,
+        public_key_package.clone(),
+    )
+    .unwrap_err();
+    assert!(error.to_string().contains("has threshold 3, config requires 2"));
+
+    assert!(
+        FrostGroup::new_for_participant(config, public_key_package, alice)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sign_all_subsets() -> Result<()> {
+    let group = FrostGroup::new_with_trusted_dealer(
+        FrostGroupConfig::two_of_three(
+            &["Alice", "Bob", "Charlie"],
+            "Quorum health check".to_string(),
+        )?,
+        &mut OsRng,
+    )?;
+    let message = b"Every quorum signs";
+    let results = group.sign_all_subsets(message, &mut OsRng)?;
+
+    let subsets: Vec<Vec<String>> =
+        results.iter().map(|(subset, _)| subset.clone()).collect();
+    assert_eq!(
+        subsets,
+        [["Alice", "Bob"], ["Alice", "Charlie"], ["Bob", "Charlie"]]
+    );
+    for (_, signature) in &results {
+        assert!(group.verify(message, signature).is_ok());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_signing_with_aad() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let message = b"Release v1.2.0";
+    let aad = b"request-id:4711";
+
+    let signers = ["Alice", "Diana"];
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+    let signature = group.round_2_sign_with_aad(
+        &signers,
+        &commitments,
+        &nonces,
+        aad,
+        message,
+    )?;
+    assert!(group.verify_with_aad(aad, message, &signature).is_ok());
+    let summary = group.public_summary();
+    assert!(summary.verify_with_aad(aad, message, &signature).is_ok());
+
+    // Any other AAD, including none, fails
+    assert!(
+        group
+            .verify_with_aad(b"request-id:4712", message, &signature)
+            .is_err()
+    );
+    assert!(group.verify_with_aad(b"", message, &signature).is_err());
+    assert!(group.verify(message, &signature).is_err());
+
+    // The length prefix keeps the AAD/message boundary unambiguous
+    assert!(
+        group
+            .verify_with_aad(b"request-id:471", b"1Release v1.2.0", &signature)
+            .is_err()
+    );
+
+    // An application message with the same bytes is not an AAD signature
+    let lookalike = aad_message(aad, message)?;
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+    let application =
+        group.round_2_sign(&signers, &commitments, &nonces, &lookalike)?;
+    assert!(group.verify_with_aad(aad, message, &application).is_err());
+    assert!(summary.verify_with_aad(aad, message, &application).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_prehashed_signing_of_huge_message() -> Result<()> {
+    let mut group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let sink = warnings.clone();
+    group.set_observer(move |w| sink.lock().unwrap().push(w.clone()));
+
+    // A 64 MiB artifact is hashed in chunks and only its digest is signed
+    let artifact = vec![0x5Au8; 64 * 1024 * 1024];
+    let digest = prehash(artifact.as_slice())?;
+    assert_eq!(digest, bc_crypto::sha256(&artifact));
+
+    let signers = ["Alice", "Diana"];
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+    let signature = group.round_2_sign_prehashed(
+        &signers,
+        &commitments,
+        &nonces,
+        &digest,
+    )?;
+    assert!(group.verify_prehashed(&digest, &signature).is_ok());
+    assert!(group.verify(&digest, &signature).is_err());
+
+    // An application message that looks like a tagged digest is not one
+    let mut lookalike = b"PM:v1/prehashed".to_vec();
+    lookalike.extend_from_slice(&digest);
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+    let application =
+        group.round_2_sign(&signers, &commitments, &nonces, &lookalike)?;
+    assert!(group.verify_prehashed(&digest, &application).is_err());
+    assert!(warnings.lock().unwrap().is_empty());
+
+    // Signing a large message directly works but warns
+    let large = &artifact[..PREHASH_RECOMMENDED_LEN + 1];
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+    let signature =
+        group.round_2_sign(&signers, &commitments, &nonces, large)?;
+    assert!(group.verify(large, &signature).is_ok());
+    assert_eq!(
+        *warnings.lock().unwrap(),
+        [GroupWarning::LargeMessage {
+            len: PREHASH_RECOMMENDED_LEN + 1,
+            threshold: PREHASH_RECOMMENDED_LEN,
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_single_signer_group() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        1,
+        &["Alice", "Bob", "Charlie"],
+        "Any one of us may sign".to_string(),
+    )?;
+    assert!(config.is_single_signer());
+    assert!(!family_config().is_single_signer());
+
+    let mut group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let sink = warnings.clone();
+    group.set_observer(move |w| sink.lock().unwrap().push(w.clone()));
+    assert_eq!(
+        *warnings.lock().unwrap(),
+        [GroupWarning::SingleSigner { max_signers: 3 }]
+    );
+
+    // Each participant signs alone
+    let message = b"Signed by one";
+    for signer in ["Alice", "Bob", "Charlie"] {
+        let (commitments, nonces) =
+            group.round_1_commit(&[signer], &mut OsRng)?;
+        let signature =
+            group.round_2_sign(&[signer], &commitments, &nonces, message)?;
+        group.verify(message, &signature)?;
+    }
+
+    // Threshold groups raise no configuration warning
+    let mut group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let sink = warnings.clone();
+    group.set_observer(move |w| sink.lock().unwrap().push(w.clone()));
+    assert_eq!(warnings.lock().unwrap().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_dkg_rounds_driven_manually() -> Result<()> {
+    use frost_ed25519::keys::dkg;
+
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Manually driven DKG".to_string(),
+    )?;
+    let names = ["Alice", "Bob", "Charlie"];
+    // participant_ids is ordered by name, which matches `names`
+    let ids: BTreeMap<_, _> =
+        names.into_iter().zip(config.participant_ids()).collect();
+    let id = |name: &str| ids[name];
+
+    // Round 1: each participant broadcasts a package, persisted as bytes
+    let mut round1_secrets = BTreeMap::new();
+    let mut round1_broadcast = BTreeMap::new();
+    for name in names {
+        let (secret, package) =
+            FrostGroup::dkg_part1(&config, name, &mut OsRng)?;
+        round1_secrets.insert(name, secret.serialize()?);
+        round1_broadcast.insert(id(name), package.serialize()?);
+    }
+    let round1_for = |name: &str| -> Result<BTreeMap<_, _>> {
+        round1_broadcast
+            .iter()
+            .filter(|(sender, _)| **sender != id(name))
+            .map(|(sender, bytes)| {
+                Ok((*sender, dkg::round1::Package::deserialize(bytes)?))
+            })
+            .collect()
+    };
+
+    // Round 2: each participant sends one package to every other
+    let mut round2_secrets = BTreeMap::new();
+    let mut round2_inbox: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+    for name in names {
+        let secret =
+            dkg::round1::SecretPackage::deserialize(&round1_secrets[name])?;
+        let (secret, outgoing) =
+            FrostGroup::dkg_part2(&config, secret, &round1_for(name)?)?;
+        round2_secrets.insert(name, secret);
+        for (recipient, package) in outgoing {
+            round2_inbox
+                .entry(recipient)
+                .or_default()
+                .insert(id(name), package);
+        }
+    }
+
+    // Round 3: every participant ends up with the same group key
+    let mut groups = Vec::new();
+    for name in names {
+        groups.push(FrostGroup::dkg_part3(
+            config.clone(),
+            &round2_secrets[name],
+            &round1_for(name)?,
+            &round2_inbox[&id(name)],
+        )?);
+    }
+    for group in &groups[1..] {
+        assert_eq!(group.verifying_key(), groups[0].verifying_key());
+    }
+    assert_eq!(groups[1].held_shares(), vec!["Bob".to_string()]);
+
+    // Missing a peer's round-1 package is rejected before running FROST
+    let (secret, _) = FrostGroup::dkg_part1(&config, "Alice", &mut OsRng)?;
+    let mut partial = round1_for("Alice")?;
+    partial.pop_first();
+    assert!(FrostGroup::dkg_part2(&config, secret, &partial).is_err());
+    assert!(FrostGroup::dkg_part1(&config, "Mallory", &mut OsRng).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_verify_bytes() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let signers = &["Alice", "Bob"];
+    let message = b"wire message";
+    let (commitments, nonces) = group.round_1_commit(signers, &mut OsRng)?;
+    let signature =
+        group.round_2_sign(signers, &commitments, &nonces, message)?;
+    let bytes = signature.serialize()?;
+    assert_eq!(bytes.len(), 64);
+    group.verify_bytes(message, &bytes)?;
+
+    // Truncated bytes are malformed
+    let err = group.verify_bytes(message, &bytes[..63]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<FrostError>(),
+        Some(&FrostError::MalformedSignature { len: 63 })
+    );
+
+    // So are 64 bytes that are not a curve point and scalar
+    let err = group.verify_bytes(message, &[0xFF; 64]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<FrostError>(),
+        Some(&FrostError::MalformedSignature { len: 64 })
+    );
+
+    // Well-formed bytes for a different message parse but do not verify
+    let err = group.verify_bytes(b"other message", &bytes).unwrap_err();
+    assert!(err.downcast_ref::<FrostError>().is_none());
+    Ok(())
+}
+
+#[test]
+fn test_round_1_commit_by_id() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let ids = [group.name_to_id("Bob")?, group.name_to_id("Diana")?];
+
+    // A seeded RNG makes both paths draw identical nonces
+    let (by_id_commitments, by_id_nonces) = group
+        .round_1_commit_by_id(&ids, &mut ChaCha20Rng::seed_from_u64(7))?;
+    let (by_name_commitments, by_name_nonces) = group.round_1_commit(
+        &["Bob", "Diana"],
+        &mut ChaCha20Rng::seed_from_u64(7),
+    )?;
+    assert_eq!(by_id_commitments, by_name_commitments);
+    assert_eq!(by_id_nonces.len(), by_name_nonces.len());
+    for (name, nonces) in &by_name_nonces {
+        assert_eq!(&by_id_nonces[&group.name_to_id(name)?], nonces);
+    }
+
+    let stranger = frost_ed25519::Identifier::try_from(99u16)?;
+    assert!(group.round_1_commit_by_id(&[stranger], &mut OsRng).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_aggregated_signature_round_trips() -> Result<()> {
+    let config = corporate_board_config();
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let signers = &["CEO", "CFO", "CTO"];
+    let message = b"archived statement";
+    let (commitments, nonces) = group.round_1_commit(signers, &mut OsRng)?;
+    let signature =
+        group.round_2_sign(signers, &commitments, &nonces, message)?;
+
+    let bytes = signature.serialize()?;
+    let reparsed = frost_ed25519::Signature::deserialize(&bytes)?;
+    assert_eq!(reparsed, signature);
+    assert_eq!(reparsed.serialize()?, bytes);
+    group.verify(message, &reparsed)?;
+    Ok(())
+}
+
+#[test]
+fn test_can_cosign_with() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+
+    // A holder rebuilt from a serialized share envelope is interchangeable
+    let envelope = ShareEnvelope::from_bytes(
+        group.export_share("Charlie")?.as_bytes(),
+    )?;
+    let charlie = FrostGroup::import_share(
+        group.config().clone(),
+        group.public_key_package().clone(),
+        &envelope,
+    )?;
+    assert!(group.can_cosign_with(&charlie));
+    assert!(charlie.can_cosign_with(&group));
+
+    // A group dealt separately from the same config is not
+    let other =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    assert!(!group.can_cosign_with(&other));
+    Ok(())
+}
+
+#[test]
+fn test_verify_with_archived_package() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let signers = ["Alice", "Diana"];
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+    let signing_package = FrostGroup::signing_package(
+        MessagePurpose::Application,
+        &commitments,
+        b"Disputed distribution",
+    );
+    let mut shares = BTreeMap::new();
+    for name in signers {
+        let share =
+            group.sign_for_participant(name, &signing_package, &nonces[name])?;
+        shares.insert(group.name_to_id(name)?, share);
+    }
+    let signature = group.aggregate(&signing_package, &shares)?;
+
+    // Archive the exact package and signature, then verify from public data
+    let archived = signing_package.serialize()?;
+    let summary = group.public_summary();
+    let (substitute, _) = group.round_1_commit(&signers, &mut OsRng)?;
+    drop(group);
+    let package = SigningPackage::deserialize(&archived)?;
+    summary.verify_with_package(&package, &signature)?;
+
+    // Substituted commitments from the same signers do not produce the
+    // signature's R, even over the same message
+    let substituted = SigningPackage::new(substitute, package.message());
+    let error = summary
+        .verify_with_package(&substituted, &signature)
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::UnboundCommitments)
+    );
+
+    // The package's message is what was signed, not the unframed bytes
+    let unframed = SigningPackage::new(
+        package.signing_commitments().clone(),
+        b"Disputed distribution",
+    );
+    assert!(summary.verify_with_package(&unframed, &signature).is_err());
+
+    // Commitments below the threshold are rejected before verifying
+    let mut partial = package.signing_commitments().clone();
+    partial.pop_first();
+    let partial = SigningPackage::new(partial, package.message());
+    let error = summary
+        .verify_with_package(&partial, &signature)
+        .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<FrostError>(),
+        Some(FrostError::InsufficientCommitments { required: 2, got: 1 })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_self_attestation() -> Result<()> {
+    let group = FrostGroup::new_with_trusted_dealer(
+        corporate_board_config(),
+        &mut OsRng,
+    )?;
+    let attestation = group.self_attest(&["CEO", "CFO", "COO"], &mut OsRng)?;
+    let summary = verify_attestation(&attestation)?;
+    assert_eq!(summary.verifying_key(), group.verifying_key());
+    assert_eq!(
+        summary.config().roster_hash()?,
+        group.config().roster_hash()?
+    );
+
+    // Swapping a participant out of the roster breaks the attestation
+    let tampered_config = FrostGroupConfig::new(
+        3,
+        &["CEO", "CFO", "CTO", "COO", "Mallory"],
+        "Corporate board governance for strategic decisions".to_string(),
+    )?;
+    let tampered = GroupAttestation::new(
+        PublicGroupSummary::new(tampered_config, *group.verifying_key()),
+        *attestation.signature(),
+    );
+    assert!(verify_attestation(&tampered).is_err());
+
+    // An application signature over the same bytes is not an attestation
+    let message = summary.attestation_message()?;
+    let signers = ["CEO", "CFO", "COO"];
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+    let signature =
+        group.round_2_sign(&signers, &commitments, &nonces, &message)?;
+    let forged = GroupAttestation::new(summary, signature);
+    assert!(verify_attestation(&forged).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_benchmark_sign() -> Result<()> {
+    let group = FrostGroup::new_with_trusted_dealer(
+        corporate_board_config(),
+        &mut OsRng,
+    )?;
+    let result = group.benchmark_sign(5, &mut OsRng)?;
+    assert_eq!(result.iterations, 5);
+    assert!(result.mean > Duration::ZERO);
+    assert!(result.median > Duration::ZERO);
+    assert!(result.median <= result.p99);
+    assert!(result.signatures_per_second > 0.0);
+
+    assert!(group.benchmark_sign(0, &mut OsRng).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_key_material_from_other_group_rejected() -> Result<()> {
+    let group_a =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let group_b =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let config = family_config();
+    let mut key_packages = BTreeMap::new();
+    for id in config.participant_ids() {
+        let name = config.participant_name(&id);
+        key_packages.insert(id, group_a.key_package(name)?.clone());
+    }
+
+    FrostGroup::new_from_key_material(
+        config.clone(),
+        key_packages.clone(),
+        group_a.public_key_package().clone(),
+    )?;
+    let error = FrostGroup::new_from_key_material(
+        config.clone(),
+        key_packages.clone(),
+        group_b.public_key_package().clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<FrostError>(),
+        Some(FrostError::KeyMaterialMismatch { .. })
+    ));
+
+    // Shares relabelled with another group's verifying key pass the
+    // per-participant checks but do not interpolate to that key
+    let foreign_key = *group_b.public_key_package().verifying_key();
+    let relabelled = key_packages
+        .iter()
+        .map(|(id, package)| {
+            let package = KeyPackage::new(
+                *id,
+                *package.signing_share(),
+                *package.verifying_share(),
+                foreign_key,
+                *package.min_signers(),
+            );
+            (*id, package)
+        })
+        .collect();
+    let spliced = PublicKeyPackage::new(
+        group_a.public_key_package().verifying_shares().clone(),
+        foreign_key,
+    );
+    let error = FrostGroup::new_from_key_material(config, relabelled, spliced)
+        .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<FrostError>(),
+        Some(FrostError::VerifyingSharesMismatch)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_clones_sign_concurrently() -> Result<()> {
+    let group = FrostGroup::new_with_trusted_dealer(
+        corporate_board_config(),
+        &mut OsRng,
+    )?;
+    let signers = ["CEO", "CTO", "CLO"];
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let group = group.clone();
+            std::thread::spawn(move || -> Result<_> {
+                let message = format!("Resolution for chain {}", i);
+                let (commitments, nonces) =
+                    group.round_1_commit(&signers, &mut OsRng)?;
+                let signature = group.round_2_sign(
+                    &signers,
+                    &commitments,
+                    &nonces,
+                    message.as_bytes(),
+                )?;
+                Ok((message, signature))
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        let (message, signature) = handle.join().expect("signing thread")?;
+        group.verify(message.as_bytes(), &signature)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_reconfigure_requires_fresh_key_material() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let key_packages = |names: &[&str]| -> Result<BTreeMap<_, _>> {
+        names
+            .iter()
+            .map(|name| {
+                let package = group.key_package(name)?.clone();
+                Ok((*package.identifier(), package))
+            })
+            .collect()
+    };
+    let old_packages = key_packages(&["Alice", "Bob", "Charlie", "Diana"])?;
+
+    // The live group only lends out its config, so edits land on a copy;
+    // the `FrostGroup::config` doc tests check that in-place edits through
+    // the group or its chain do not compile
+    let copy = group
+        .config()
+        .clone()
+        .reconfigure()
+        .charter("A different purpose".to_string())
+        .build()?;
+    assert_eq!(copy.charter(), "A different purpose");
+    assert_eq!(group.config(), &family_config());
+
+    // Raising the threshold over the same roster needs new shares
+    let stricter = group.clone().reconfigure().min_signers(3).build()?;
+    assert_eq!(stricter.min_signers(), 3);
+    assert_eq!(stricter.participant_ids(), family_config().participant_ids());
+    assert!(
+        FrostGroup::new_from_key_material(
+            stricter.clone(),
+            old_packages.clone(),
+            group.public_key_package().clone(),
+        )
+        .is_err()
+    );
+    let rekeyed = FrostGroup::new_with_trusted_dealer(stricter, &mut OsRng)?;
+    assert_ne!(rekeyed.verifying_key(), group.verifying_key());
+
+    // A new member has no share among the old ones
+    let grown = group.clone().reconfigure().add_participant("Erin").build()?;
+    assert_eq!(
+        grown.participant_names_in_id_order(),
+        ["Alice", "Bob", "Charlie", "Diana", "Erin"]
+    );
+    assert!(
+        FrostGroup::new_from_key_material(
+            grown.clone(),
+            old_packages,
+            group.public_key_package().clone(),
+        )
+        .is_err()
+    );
+    let shrunk = FrostGroup::new_with_trusted_dealer(
+        grown.reconfigure().remove_participant("Diana").build()?,
+        &mut OsRng,
+    )?;
+    assert_eq!(
+        shrunk.participant_names_by_id(),
+        ["Alice", "Bob", "Charlie", "Erin"]
+    );
+
+    // Duplicate names are caught when building
+    assert!(group.reconfigure().add_participant("Bob").build().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_malformed_signing_input_is_an_error() -> Result<()> {
+    let group =
+        FrostGroup::new_with_trusted_dealer(family_config(), &mut OsRng)?;
+    let signers = ["Alice", "Bob"];
+    let message = b"malformed ceremony";
+    let (commitments, nonces) = group.round_1_commit(&signers, &mut OsRng)?;
+
+    // A signer without nonces used to panic on the map index
+    let mut missing = nonces.clone();
+    missing.remove("Bob");
+    let error = group
+        .round_2_sign(&signers, &commitments, &missing, message)
+        .unwrap_err();
+    assert!(error.to_string().contains("Bob"));
+
+    // Commitments that do not match the nonces are rejected, not signed
+    let (other, _) = group.round_1_commit(&signers, &mut OsRng)?;
+    let mut swapped = commitments.clone();
+    let bob = group.name_to_id("Bob")?;
+    swapped.insert(bob, other[&bob]);
+    assert!(
+        group
+            .round_2_sign(&signers, &swapped, &nonces, message)
+            .is_err()
+    );
+
+    // Signers missing from the commitments are rejected
+    let mut short = commitments.clone();
+    short.remove(&bob);
+    assert!(group.round_2_sign(&signers, &short, &nonces, message).is_err());
+
+    group.round_2_sign(&signers, &commitments, &nonces, message)?;
+    Ok(())
+}
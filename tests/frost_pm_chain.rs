@@ -1,9 +1,29 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
 use anyhow::Result;
-use dcbor::Date;
+use dcbor::{CBOR, CBOREncodable, Date};
+use frost_ed25519::{
+    Identifier, Signature, SigningKey, VerifyingKey,
+    round1::{SigningCommitments, SigningNonces},
+};
 use frost_pm_test::{
-    FrostGroup, FrostGroupConfig, pm_chain::FrostPmChain, rand_core::OsRng,
+    CeremonyState, ChainManifest, CommitmentAck, FrostError, FrostGroup,
+    FrostGroupConfig, GenesisProof, MarkRecord, PrecommitReceipt,
+    ProposalCheck, SharedChain,
+    manifest::verify_manifest_against_group,
+    message::{MarkInfo, MessagePurpose, encode_date, normalize_date},
+    pm_chain::{
+        FrostPmChain, KdfVersion, Link, Stats, prev_commitment_matches,
+        verify_append, verify_chain_with_signatures, verify_compact_proof,
+    },
+    rand_core::{OsRng, RngCore},
 };
-use provenance_mark::ProvenanceMarkResolution;
+use provenance_mark::{ProvenanceMark, ProvenanceMarkResolution};
+use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
 
 #[test]
 fn frost_controls_pm_chain() -> Result<()> {
@@ -24,7 +44,8 @@ fn frost_controls_pm_chain() -> Result<()> {
     let signers = &["Alice", "Bob"];
     let (commitments_0, nonces_0) =
         group.round_1_commit(signers, &mut OsRng)?;
-    let signature_0 = group.round_2_sign(
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
         signers,
         &commitments_0,
         &nonces_0,
@@ -36,7 +57,7 @@ fn frost_controls_pm_chain() -> Result<()> {
         group.round_1_commit(signers, &mut OsRng)?;
 
     // Genesis from Alice+Bob
-    let (mut chain, mark_0) = FrostPmChain::new_chain(
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain(
         res,
         date_0,
         info_0,
@@ -45,7 +66,7 @@ fn frost_controls_pm_chain() -> Result<()> {
         &commitments_1,
     )?;
 
-    println!("Genesis mark created: {}", mark_0.identifier());
+    println!("Genesis mark created: {}", mark_0.id_hex());
     assert!(mark_0.is_genesis());
 
     // Create second mark with a different "image"
@@ -54,7 +75,8 @@ fn frost_controls_pm_chain() -> Result<()> {
 
     // Client generates message and Round-2 signature
     let message_1 = chain.message_next(date_1, info_1);
-    let signature_1 = chain.group().round_2_sign(
+    let signature_1 = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
         signers,
         &commitments_1,
         &nonces_1,
@@ -73,7 +95,7 @@ fn frost_controls_pm_chain() -> Result<()> {
         &commitments_2,
     )?;
 
-    println!("Mark 1 created: {}", mark_1.identifier());
+    println!("Mark 1 created: {}", mark_1.id_hex());
 
     // Create mark 2 with yet another "image"
     let info_2 = Some("mark 2 image bytes");
@@ -81,7 +103,8 @@ fn frost_controls_pm_chain() -> Result<()> {
 
     // Client generates message and Round-2 signature
     let message_2 = chain.message_next(date_2, info_2);
-    let signature_2 = chain.group().round_2_sign(
+    let signature_2 = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
         signers,
         &commitments_2,
         &nonces_2,
@@ -100,7 +123,7 @@ fn frost_controls_pm_chain() -> Result<()> {
         &commitments_3,
     )?;
 
-    println!("Third mark created: {}", mark_2.identifier());
+    println!("Third mark created: {}", mark_2.id_hex());
 
     // Verify the invariants with the PM crate
     assert!(mark_0.is_genesis());
@@ -150,7 +173,8 @@ fn frost_pm_chain_date_monotonicity() -> Result<()> {
     let signers = &["Alice", "Bob"];
     let (commitments_0, nonces_0) =
         group.round_1_commit(signers, &mut OsRng)?;
-    let signature_0 = group.round_2_sign(
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
         signers,
         &commitments_0,
         &nonces_0,
@@ -161,7 +185,7 @@ fn frost_pm_chain_date_monotonicity() -> Result<()> {
     let (commitments_1, nonces_1) =
         group.round_1_commit(signers, &mut OsRng)?;
 
-    let (mut chain, _mark_0) = FrostPmChain::new_chain(
+    let (mut chain, _mark_0, _) = FrostPmChain::new_chain(
         res,
         date_0,
         info_0,
@@ -176,7 +200,8 @@ fn frost_pm_chain_date_monotonicity() -> Result<()> {
 
     // Even though this will fail, we need to provide a signature
     let message_fail = chain.message_next(earlier_date, Some("test content 2"));
-    let signature_fail = chain.group().round_2_sign(
+    let signature_fail = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
         signers,
         &commitments_1,
         &nonces_1,
@@ -224,7 +249,8 @@ fn frost_pm_different_signer_combinations() -> Result<()> {
     let signers = &["Alice", "Bob", "Charlie"];
     let (commitments_0, nonces_0) =
         group.round_1_commit(signers, &mut OsRng)?;
-    let signature_0 = group.round_2_sign(
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
         signers,
         &commitments_0,
         &nonces_0,
@@ -236,7 +262,7 @@ fn frost_pm_different_signer_combinations() -> Result<()> {
         group.round_1_commit(signers, &mut OsRng)?;
 
     // Genesis with Alice, Bob, Charlie
-    let (mut chain, mark_0) = FrostPmChain::new_chain(
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain(
         res,
         date_0,
         info_0,
@@ -249,7 +275,8 @@ fn frost_pm_different_signer_combinations() -> Result<()> {
     let date_1 = Date::now();
     let info_1 = Some("test content 2");
     let message_1 = chain.message_next(date_1, info_1);
-    let signature_1 = chain.group().round_2_sign(
+    let signature_1 = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
         signers,
         &commitments_1,
         &nonces_1,
@@ -314,7 +341,8 @@ fn frost_pm_all_resolutions() -> Result<()> {
         let signers = &["Alice", "Bob"];
         let (commitments_0, nonces_0) =
             group.round_1_commit(signers, &mut OsRng)?;
-        let signature_0 = group.round_2_sign(
+        let signature_0 = group.round_2_sign_as(
+            MessagePurpose::Genesis,
             signers,
             &commitments_0,
             &nonces_0,
@@ -326,7 +354,7 @@ fn frost_pm_all_resolutions() -> Result<()> {
             group.round_1_commit(signers, &mut OsRng)?;
 
         // Genesis
-        let (mut chain, mark_0) = FrostPmChain::new_chain(
+        let (mut chain, mark_0, _) = FrostPmChain::new_chain(
             res,
             date_0,
             info_0,
@@ -343,7 +371,7 @@ fn frost_pm_all_resolutions() -> Result<()> {
         assert_eq!(mark_0.chain_id(), mark_0.key()); // Genesis invariant
         println!(
             "  ✓ Genesis mark: {} ({})",
-            mark_0.identifier(),
+            mark_0.id_hex(),
             mark_0.key().len()
         );
 
@@ -351,7 +379,8 @@ fn frost_pm_all_resolutions() -> Result<()> {
         let date_1 = Date::now();
         let info_1 = Some("test content 1");
         let message_1 = chain.message_next(date_1, info_1);
-        let signature_1 = chain.group().round_2_sign(
+        let signature_1 = chain.group().round_2_sign_as(
+            MessagePurpose::Mark,
             signers,
             &commitments_1,
             &nonces_1,
@@ -377,7 +406,7 @@ fn frost_pm_all_resolutions() -> Result<()> {
         assert_eq!(mark_1.chain_id(), mark_0.chain_id());
         println!(
             "  ✓ Mark 1: {} ({})",
-            mark_1.identifier(),
+            mark_1.id_hex(),
             mark_1.key().len()
         );
 
@@ -385,7 +414,8 @@ fn frost_pm_all_resolutions() -> Result<()> {
         let date_2 = Date::now();
         let info_2 = Some("test content 3");
         let message_2 = chain.message_next(date_2, info_2);
-        let signature_2 = chain.group().round_2_sign(
+        let signature_2 = chain.group().round_2_sign_as(
+            MessagePurpose::Mark,
             signers,
             &commitments_2,
             &nonces_2,
@@ -411,7 +441,7 @@ fn frost_pm_all_resolutions() -> Result<()> {
         assert_eq!(mark_2.chain_id(), mark_0.chain_id());
         println!(
             "  ✓ Third mark: {} ({})",
-            mark_2.identifier(),
+            mark_2.id_hex(),
             mark_2.key().len()
         );
 
@@ -426,3 +456,2321 @@ fn frost_pm_all_resolutions() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn frost_pm_verify_append_without_state() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Stateless append verification test chain".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Medium;
+    let date_0 = Date::now();
+    let info_0 = Some("genesis content");
+    let message_0 = FrostPmChain::message_0(&config, res, date_0, info_0);
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    let signers = &["Alice", "Charlie"];
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (commitments_1, nonces_1) =
+        group.round_1_commit(signers, &mut OsRng)?;
+
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0,
+        group.clone(),
+        signature_0,
+        &commitments_1,
+    )?;
+
+    // Mark 1
+    let date_1 = Date::now();
+    let info_1 = Some("content 1");
+    let message_1 = chain.message_next(date_1, info_1);
+    let signature_1 = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        &commitments_1,
+        &nonces_1,
+        message_1.as_bytes(),
+    )?;
+    let (commitments_2, nonces_2) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let mark_1 = chain.append_mark(
+        date_1,
+        info_1,
+        &commitments_1,
+        signature_1,
+        &commitments_2,
+    )?;
+
+    // Mark 2
+    let date_2 = Date::now();
+    let info_2 = Some("content 2");
+    let message_2 = chain.message_next(date_2, info_2);
+    let signature_2 = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        &commitments_2,
+        &nonces_2,
+        message_2.as_bytes(),
+    )?;
+    let (commitments_3, _nonces_3) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let mark_2 = chain.append_mark(
+        date_2,
+        info_2,
+        &commitments_2,
+        signature_2,
+        &commitments_3,
+    )?;
+
+    // An auditor with only the marks, signatures, and group can verify
+    verify_append(&group, &mark_0, &mark_1, &signature_1)?;
+    verify_append(&group, &mark_1, &mark_2, &signature_2)?;
+
+    // Signature for a different mark is rejected
+    assert!(verify_append(&group, &mark_0, &mark_1, &signature_2).is_err());
+
+    // Non-adjacent marks are rejected
+    let result = verify_append(&group, &mark_0, &mark_2, &signature_2);
+    assert!(result.unwrap_err().to_string().contains("sequence gap"));
+
+    // Reversed order is rejected
+    assert!(verify_append(&group, &mark_1, &mark_0, &signature_1).is_err());
+
+    // A forged predecessor at the last seq has no successor
+    let last = ProvenanceMark::new(
+        mark_0.res(),
+        mark_0.key().to_vec(),
+        mark_1.key().to_vec(),
+        mark_0.chain_id().to_vec(),
+        u32::MAX,
+        mark_0.date(),
+        None::<&str>,
+    )?;
+    let result = verify_append(&group, &last, &mark_1, &signature_1);
+    assert!(result.unwrap_err().to_string().contains("no mark can follow"));
+
+    // A mark with tampered info no longer matches the signature
+    let tampered = ProvenanceMark::new(
+        mark_1.res(),
+        mark_1.key().to_vec(),
+        mark_2.key().to_vec(),
+        mark_1.chain_id().to_vec(),
+        mark_1.seq(),
+        mark_1.date(),
+        Some("tampered content"),
+    )?;
+    assert!(verify_append(&group, &mark_0, &tampered, &signature_1).is_err());
+
+    // A different group cannot vouch for the append
+    let other_group = FrostGroup::new_with_trusted_dealer(
+        group.config().clone(),
+        &mut OsRng,
+    )?;
+    assert!(
+        verify_append(&other_group, &mark_0, &mark_1, &signature_1).is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_append_mark_timed() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Append timing test chain".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::High;
+    let date_0 = Date::now();
+    let info_0 = None::<String>;
+    let message_0 =
+        FrostPmChain::message_0(&config, res, date_0, info_0.clone());
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    let signers = &["Alice", "Bob"];
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (commitments_1, nonces_1) =
+        group.round_1_commit(signers, &mut OsRng)?;
+
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0,
+        group,
+        signature_0,
+        &commitments_1,
+    )?;
+
+    let date_1 = Date::now();
+    let info_1 = Some("timed content");
+    let message_1 = chain.message_next(date_1, info_1);
+    let signature_1 = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        &commitments_1,
+        &nonces_1,
+        message_1.as_bytes(),
+    )?;
+    let (commitments_2, _nonces_2) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+
+    let (mark_1, timings) = chain.append_mark_timed(
+        date_1,
+        info_1,
+        &commitments_1,
+        signature_1,
+        &commitments_2,
+    )?;
+    assert!(mark_0.precedes(&mark_1));
+
+    // Every phase is recorded
+    assert!(timings.root > Duration::ZERO);
+    assert!(timings.kdf > Duration::ZERO);
+    assert!(timings.verify > Duration::ZERO);
+    assert!(timings.precommit > Duration::ZERO);
+    assert!(timings.finalize > Duration::ZERO);
+
+    // Phases are contiguous, so they account for the whole append
+    let sum = timings.phases_sum();
+    assert!(sum <= timings.total);
+    assert!(timings.total - sum < Duration::from_millis(1));
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_predecessor_key_bound_into_genesis() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Key continuity test chain".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Quartile;
+    let date_0 = Date::now();
+    let info_0 = None::<String>;
+
+    // The prior single-signer identity and an unrelated key
+    let old_key = VerifyingKey::from(&SigningKey::new(&mut OsRng));
+    let other_key = VerifyingKey::from(&SigningKey::new(&mut OsRng));
+
+    let plain_message =
+        FrostPmChain::message_0(&config, res, date_0, info_0.clone());
+    let message_0 = FrostPmChain::message_0_with_predecessor(
+        &config,
+        res,
+        date_0,
+        info_0.clone(),
+        Some(&old_key),
+    );
+    let other_message = FrostPmChain::message_0_with_predecessor(
+        &config,
+        res,
+        date_0,
+        info_0.clone(),
+        Some(&other_key),
+    );
+    assert_ne!(message_0, plain_message);
+    assert_ne!(message_0, other_message);
+    assert!(message_0.starts_with(&plain_message));
+
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let signers = &["Alice", "Bob"];
+    let sign = |message: &str| -> Result<Signature> {
+        let (commitments, nonces) =
+            group.round_1_commit(signers, &mut OsRng)?;
+        group.round_2_sign_as(
+            MessagePurpose::Genesis,
+            signers,
+            &commitments,
+            &nonces,
+            message.as_bytes(),
+        )
+    };
+    let (commitments_1, _nonces_1) =
+        group.round_1_commit(signers, &mut OsRng)?;
+
+    // A signature over the message without the predecessor key is rejected
+    let result = FrostPmChain::new_chain_with_predecessor(
+        res,
+        date_0,
+        info_0.clone(),
+        group.clone(),
+        sign(&plain_message)?,
+        &commitments_1,
+        Some(old_key),
+    );
+    assert!(result.is_err());
+
+    // A signature binding a different predecessor key is rejected
+    let result = FrostPmChain::new_chain_with_predecessor(
+        res,
+        date_0,
+        info_0.clone(),
+        group.clone(),
+        sign(&other_message)?,
+        &commitments_1,
+        Some(old_key),
+    );
+    assert!(result.is_err());
+
+    // The matching signature succeeds and the key is exposed
+    let (chain, mark_0, _) = FrostPmChain::new_chain_with_predecessor(
+        res,
+        date_0,
+        info_0,
+        group.clone(),
+        sign(&message_0)?,
+        &commitments_1,
+        Some(old_key),
+    )?;
+    assert!(mark_0.is_genesis());
+    assert_eq!(chain.predecessor_key(), Some(&old_key));
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_predecessor_chain_bound_into_genesis() -> Result<()> {
+    // Chain A runs for a while under the original group
+    let signers = &["Alice", "Bob"];
+    let res = ProvenanceMarkResolution::Quartile;
+    let (mut chain_a, mark_0, commitments, nonces) =
+        start_chain(res, signers)?;
+    let (tip, ..) =
+        append_next(&mut chain_a, signers, &commitments, &nonces, "last")?;
+    let tip_hash = tip.hash().to_vec();
+
+    // Chain B starts under a rotated group and points at A's tip
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Rotated group".to_string(),
+    )?;
+    let date_0 = Date::now();
+    let no_info = None::<&str>;
+    let plain_message = FrostPmChain::message_0(&config, res, date_0, no_info);
+    let message_0 = FrostPmChain::message_0_with_predecessor_chain(
+        &config,
+        res,
+        date_0,
+        no_info,
+        &tip_hash,
+    );
+    let other_message = FrostPmChain::message_0_with_predecessor_chain(
+        &config,
+        res,
+        date_0,
+        no_info,
+        mark_0.hash(),
+    );
+    assert!(message_0.starts_with(&plain_message));
+    assert_ne!(message_0, other_message);
+
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    assert_ne!(group.verifying_key(), chain_a.group().verifying_key());
+    let sign = |message: &str| -> Result<Signature> {
+        let (commitments, nonces) =
+            group.round_1_commit(signers, &mut OsRng)?;
+        group.round_2_sign_as(
+            MessagePurpose::Genesis,
+            signers,
+            &commitments,
+            &nonces,
+            message.as_bytes(),
+        )
+    };
+    let (commitments_1, _) = group.round_1_commit(signers, &mut OsRng)?;
+    let new_chain_b = |signature| {
+        FrostPmChain::new_chain_with_predecessor_chain(
+            &tip_hash,
+            res,
+            date_0,
+            no_info,
+            group.clone(),
+            signature,
+            &commitments_1,
+        )
+    };
+
+    // Signatures that omit the pointer or bind another mark are rejected
+    assert!(new_chain_b(sign(&plain_message)?).is_err());
+    assert!(new_chain_b(sign(&other_message)?).is_err());
+
+    let signature_0 = sign(&message_0)?;
+    let (chain_b, mark_b, _) = new_chain_b(signature_0)?;
+    assert_eq!(chain_b.predecessor_chain(), Some(tip_hash.as_slice()));
+    assert_eq!(chain_a.predecessor_chain(), None);
+
+    // Auditors replaying chain B must be told the pointer it binds
+    let genesis = GenesisProof::new(mark_b, signature_0);
+    assert!(FrostPmChain::replay_verify(&group, &genesis, &[]).is_err());
+    let genesis = genesis.with_predecessor_chain(&tip_hash);
+    assert_eq!(genesis.predecessor_chain(), Some(tip_hash.as_slice()));
+    FrostPmChain::replay_verify(&group, &genesis, &[])?;
+    Ok(())
+}
+
+#[test]
+fn frost_pm_append_rejects_undersized_commitments() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Commitment size validation test chain".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Low;
+    let date_0 = Date::now();
+    let info_0 = None::<String>;
+    let message_0 =
+        FrostPmChain::message_0(&config, res, date_0, info_0.clone());
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    let signers = &["Alice", "Bob"];
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (commitments_1, nonces_1) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let (mut chain, _mark_0, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0,
+        group,
+        signature_0,
+        &commitments_1,
+    )?;
+
+    let date_1 = Date::now();
+    let info_1 = Some("content 1");
+    let message_1 = chain.message_next(date_1, info_1);
+    let signature_1 = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        &commitments_1,
+        &nonces_1,
+        message_1.as_bytes(),
+    )?;
+
+    // Only a single participant's commitment for the next sequence
+    let (mut commitments_2, _nonces_2) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    commitments_2.pop_last();
+    assert_eq!(commitments_2.len(), 1);
+
+    let result = chain.append_mark(
+        date_1,
+        info_1,
+        &commitments_1,
+        signature_1,
+        &commitments_2,
+    );
+    let error = result.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::InsufficientCommitments { required: 2, got: 1 })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_ceremony_state_snapshot_restore() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Resumable ceremony test chain".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Medium;
+    let date_0 = Date::now();
+    let info_0 = None::<String>;
+    let message_0 =
+        FrostPmChain::message_0(&config, res, date_0, info_0.clone());
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    let signers = &["Bob", "Charlie"];
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (commitments_1, nonces_1) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0,
+        group,
+        signature_0,
+        &commitments_1,
+    )?;
+
+    // Coordinator begins the append after Round-1 and persists the state
+    let (commitments_2, _nonces_2) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let state = chain.begin_append(
+        Date::now(),
+        Some("resumed content"),
+        signers,
+        &commitments_1,
+        &commitments_2,
+    )?;
+    let bytes = state.to_bytes()?;
+
+    // ...restart...
+    let restored = CeremonyState::from_bytes(&bytes)?;
+    assert_eq!(restored, state);
+    assert_eq!(restored.seq(), 1);
+    assert_eq!(restored.signers(), &["Bob", "Charlie"]);
+
+    // Signers complete Round-2 over the persisted message
+    let signature_1 = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        restored.commitments(),
+        &nonces_1,
+        restored.message().as_bytes(),
+    )?;
+    let mark_1 = chain.complete_append(&restored, signature_1)?;
+    assert!(mark_0.precedes(&mark_1));
+
+    // The same state cannot be completed twice
+    assert!(chain.complete_append(&restored, signature_1).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_new_chain_rejects_invalid_commitments_1() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Genesis quorum validation test chain".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Low;
+    let date_0 = Date::now();
+    let info_0 = None::<String>;
+    let message_0 =
+        FrostPmChain::message_0(&config, res, date_0, info_0.clone());
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    let signers = &["Alice", "Bob"];
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+
+    // Fewer commitments than the threshold
+    let (mut commitments_1, _nonces_1) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    commitments_1.pop_first();
+    let error = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0.clone(),
+        group.clone(),
+        signature_0,
+        &commitments_1,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::InsufficientCommitments { required: 2, got: 1 })
+    );
+
+    // Commitments from an identifier outside the group
+    let outsiders = FrostGroup::new_with_trusted_dealer(
+        FrostGroupConfig::new(
+            2,
+            &["Alice", "Bob", "Charlie", "Diana"],
+            "Another group".to_string(),
+        )?,
+        &mut OsRng,
+    )?;
+    let (foreign_commitments, _nonces) =
+        outsiders.round_1_commit(&["Alice", "Diana"], &mut OsRng)?;
+    let error = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0,
+        group,
+        signature_0,
+        &foreign_commitments,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::UnknownCommitmentParticipant)
+    );
+
+    Ok(())
+}
+
+/// Build a two-append chain using only the provided RNG for key generation
+/// and every Round-1 commitment
+fn seeded_chain_marks(seed: u64) -> Result<Vec<ProvenanceMark>> {
+    let mut rng = ChaCha20Rng::seed_from_u64(seed);
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Deterministic RNG test chain".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Quartile;
+    let date_0 = Date::from_ymd(2025, 1, 1);
+    let info_0 = Some("seeded genesis");
+    let message_0 = FrostPmChain::message_0(&config, res, date_0, info_0);
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut rng)?;
+
+    let signers = &["Alice", "Bob"];
+    let (commitments_0, nonces_0) = group.round_1_commit(signers, &mut rng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (mut commitments, mut nonces) =
+        group.round_1_commit(signers, &mut rng)?;
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0,
+        group,
+        signature_0,
+        &commitments,
+    )?;
+
+    let mut marks = vec![mark_0];
+    for day in 2..=3 {
+        let date = Date::from_ymd(2025, 1, day);
+        let info = Some(format!("seeded mark {}", day));
+        let message = chain.message_next(date, info.clone());
+        let signature = chain.group().round_2_sign_as(
+            MessagePurpose::Mark,
+            signers,
+            &commitments,
+            &nonces,
+            message.as_bytes(),
+        )?;
+        let (next_commitments, next_nonces) =
+            chain.group().round_1_commit(signers, &mut rng)?;
+        marks.push(chain.append_mark(
+            date,
+            info,
+            &commitments,
+            signature,
+            &next_commitments,
+        )?);
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+    Ok(marks)
+}
+
+#[test]
+fn frost_pm_seeded_rng_is_reproducible() -> Result<()> {
+    let marks = seeded_chain_marks(42)?;
+    assert!(ProvenanceMark::is_sequence_valid(&marks));
+
+    // Same seed, same chain
+    assert_eq!(seeded_chain_marks(42)?, marks);
+
+    // Different seed, different chain
+    assert_ne!(seeded_chain_marks(43)?, marks);
+
+    Ok(())
+}
+
+type Commitments = BTreeMap<Identifier, SigningCommitments>;
+type Nonces = BTreeMap<String, SigningNonces>;
+
+/// Create a 2-of-3 Alice/Bob/Charlie chain signed by `signers`, returning the
+/// chain, its genesis mark, and the Round-1 state precommitted for seq=1
+fn start_chain(
+    res: ProvenanceMarkResolution,
+    signers: &[&str],
+) -> Result<(FrostPmChain, ProvenanceMark, Commitments, Nonces)> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Test chain".to_string(),
+    )?;
+    let date_0 = Date::now();
+    let info_0 = None::<String>;
+    let message_0 =
+        FrostPmChain::message_0(&config, res, date_0, info_0.clone());
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (commitments_1, nonces_1) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let (chain, mark_0, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0,
+        group,
+        signature_0,
+        &commitments_1,
+    )?;
+    Ok((chain, mark_0, commitments_1, nonces_1))
+}
+
+/// Sign and append the next mark, returning it with its signature and the
+/// Round-1 state precommitted for the following mark
+fn append_next(
+    chain: &mut FrostPmChain,
+    signers: &[&str],
+    commitments: &Commitments,
+    nonces: &Nonces,
+    info: &str,
+) -> Result<(ProvenanceMark, Signature, Commitments, Nonces)> {
+    let date = Date::now();
+    let message = chain.message_next(date, Some(info));
+    let signature = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        commitments,
+        nonces,
+        message.as_bytes(),
+    )?;
+    let (next_commitments, next_nonces) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let mark = chain.append_mark(
+        date,
+        Some(info),
+        commitments,
+        signature,
+        &next_commitments,
+    )?;
+    Ok((mark, signature, next_commitments, next_nonces))
+}
+
+#[test]
+fn frost_pm_append_mark_trusted_matches_checked() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, _, commitments, nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    let mut trusted = chain.fork();
+
+    let date = Date::now();
+    let message = chain.message_next(date, Some("trusted"));
+    let signature = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        &commitments,
+        &nonces,
+        message.as_bytes(),
+    )?;
+    chain.group().verify_as(
+        MessagePurpose::Mark,
+        message.as_bytes(),
+        &signature,
+    )?;
+    let (next_commitments, _) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+
+    let checked_mark = chain.append_mark(
+        date,
+        Some("trusted"),
+        &commitments,
+        signature,
+        &next_commitments,
+    )?;
+    let trusted_mark = trusted.append_mark_trusted(
+        date,
+        Some("trusted"),
+        &commitments,
+        signature,
+        &next_commitments,
+    )?;
+    assert_eq!(trusted_mark, checked_mark);
+    assert_eq!(
+        trusted.stats().signatures_verified + 1,
+        chain.stats().signatures_verified
+    );
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_verify_chain_with_signature_registry() -> Result<()> {
+    let config = FrostGroupConfig::two_of_three(
+        &["Alice", "Bob", "Charlie"],
+        "Signature registry".to_string(),
+    )?;
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let res = ProvenanceMarkResolution::Quartile;
+    let date_0 = Date::from_timestamp(1_750_000_000.0);
+    let signers = &["Bob", "Charlie"];
+    let message_0 =
+        FrostPmChain::message_0(group.config(), res, date_0, Some("first"));
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (mut commitments, mut nonces) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        Some("first"),
+        group,
+        signature_0,
+        &commitments,
+    )?;
+
+    // Marks are published; the operator keeps the signatures
+    let mut marks = vec![mark_0];
+    let mut registry = vec![signature_0];
+    for i in 1..=3 {
+        let (mark, signature, next_commitments, next_nonces) = append_next(
+            &mut chain,
+            signers,
+            &commitments,
+            &nonces,
+            &format!("mark {}", i),
+        )?;
+        marks.push(mark);
+        registry.push(signature);
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+    let group = chain.group();
+    verify_chain_with_signatures(group, &marks, &registry)?;
+
+    // A swapped pair of signatures no longer lines up with the marks
+    let mut swapped = registry.clone();
+    swapped.swap(1, 2);
+    assert!(verify_chain_with_signatures(group, &marks, &swapped).is_err());
+
+    // So does a genesis signature swapped for an append signature
+    let mut swapped = registry.clone();
+    swapped.swap(0, 3);
+    assert!(verify_chain_with_signatures(group, &marks, &swapped).is_err());
+
+    // And a registry missing an entry
+    let short = &registry[..3];
+    assert!(verify_chain_with_signatures(group, &marks, short).is_err());
+
+    // The genesis signature cannot be transplanted onto a re-keyed genesis
+    let genesis = &marks[0];
+    let key: Vec<u8> = genesis.key().iter().map(|b| b ^ 0xff).collect();
+    let rekeyed = ProvenanceMark::new(
+        genesis.res(),
+        key.clone(),
+        marks[1].key().to_vec(),
+        key,
+        0,
+        genesis.date(),
+        genesis.info(),
+    )?;
+    verify_chain_with_signatures(group, &marks[..1], &registry[..1])?;
+    let result =
+        verify_chain_with_signatures(group, &[rekeyed], &registry[..1]);
+    assert!(result.unwrap_err().to_string().contains("genesis key"));
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_validate_proposed_mark() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, _, commitments, nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    let (_, _, commitments, nonces) =
+        append_next(&mut chain, signers, &commitments, &nonces, "tip")?;
+
+    let sign = |date: Date| {
+        let message = chain.message_next(date, Some("proposal"));
+        chain.group().round_2_sign_as(
+            MessagePurpose::Mark,
+            signers,
+            &commitments,
+            &nonces,
+            message.as_bytes(),
+        )
+    };
+    let (next_commitments, _) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+
+    // A sound proposal passes every check
+    let date = Date::now();
+    let report = chain.validate_proposed_mark(
+        date,
+        Some("proposal"),
+        &commitments,
+        sign(date)?,
+        &next_commitments,
+    );
+    assert!(report.is_valid(), "{:?}", report);
+    assert_eq!(report.results().len(), 7);
+
+    // A back-dated proposal replaying its commitments fails two checks, and
+    // both are reported
+    let past = Date::from_ymd(2000, 1, 1);
+    let report = chain.validate_proposed_mark(
+        past,
+        Some("proposal"),
+        &commitments,
+        sign(past)?,
+        &commitments,
+    );
+    assert!(!report.is_valid());
+    assert_eq!(
+        report.failures(),
+        [ProposalCheck::DateMonotonic, ProposalCheck::FreshNextCommitments]
+    );
+
+    // Validation never appends
+    assert_eq!(chain.last_mark().seq(), 1);
+    Ok(())
+}
+
+#[test]
+fn frost_pm_chain_length() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, mark_0, mut commitments, mut nonces) =
+        start_chain(ProvenanceMarkResolution::Low, signers)?;
+    let mut history = vec![mark_0];
+    assert_eq!(chain.chain_length(), 1);
+
+    for i in 1..=5 {
+        let (mark, _signature, next_commitments, next_nonces) = append_next(
+            &mut chain,
+            signers,
+            &commitments,
+            &nonces,
+            &format!("mark {}", i),
+        )?;
+        history.push(mark);
+        commitments = next_commitments;
+        nonces = next_nonces;
+        assert_eq!(chain.chain_length(), history.len() as u64);
+        assert_eq!(chain.last_mark(), history.last().unwrap());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_new_chain_with_identity() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Product identity test chain".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Medium;
+    let date_0 = Date::from_ymd(2025, 6, 1);
+    let info_0 = Some("product line");
+    let message_0 = FrostPmChain::message_0(&config, res, date_0, info_0);
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+
+    let signers = &["Alice", "Bob"];
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (commitments_1, _nonces_1) =
+        group.round_1_commit(signers, &mut OsRng)?;
+
+    // Identical inputs apart from the identity
+    let genesis_for = |identity: &[u8]| {
+        FrostPmChain::new_chain_with_identity(
+            identity,
+            res,
+            date_0,
+            info_0,
+            group.clone(),
+            signature_0,
+            &commitments_1,
+        )
+    };
+    let (_chain_a, mark_a, _) = genesis_for(b"SKU-1001")?;
+    let (_chain_b, mark_b, _) = genesis_for(b"SKU-2002")?;
+    let (_chain_a2, mark_a2, _) = genesis_for(b"SKU-1001")?;
+    let (_plain, mark_plain, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        info_0,
+        group.clone(),
+        signature_0,
+        &commitments_1,
+    )?;
+
+    for mark in [&mark_a, &mark_b, &mark_plain] {
+        assert!(mark.is_genesis());
+        assert_eq!(mark.chain_id(), mark.key());
+    }
+    assert_ne!(mark_a.chain_id(), mark_b.chain_id());
+    assert_ne!(mark_a.chain_id(), mark_plain.chain_id());
+    assert_eq!(mark_a.chain_id(), mark_a2.chain_id());
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_prev_commitment_matches_audit() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let res = ProvenanceMarkResolution::Quartile;
+    let (mut chain, mark_0, commitments, nonces) =
+        start_chain(res, signers)?;
+    let (mark_1, _signature, _commitments, _nonces) =
+        append_next(&mut chain, signers, &commitments, &nonces, "audited")?;
+
+    // The real next key is the following mark's key
+    assert!(prev_commitment_matches(&mark_0, mark_1.key())?);
+
+    // A random candidate of the right length does not match
+    let mut random_key = vec![0u8; res.link_length()];
+    OsRng.fill_bytes(&mut random_key);
+    assert!(!prev_commitment_matches(&mark_0, &random_key)?);
+
+    // Nor does the mark's own key
+    assert!(!prev_commitment_matches(&mark_0, mark_0.key())?);
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_current_info() -> Result<()> {
+    let signers = &["Bob", "Charlie"];
+    let (mut chain, _mark_0, commitments, nonces) =
+        start_chain(ProvenanceMarkResolution::Low, signers)?;
+    assert_eq!(chain.current_info(), None);
+
+    append_next(&mut chain, signers, &commitments, &nonces, "tip info")?;
+    assert_eq!(chain.current_info(), Some(CBOR::from("tip info")));
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_fork() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, _mark_0, commitments, nonces) =
+        start_chain(ProvenanceMarkResolution::Medium, signers)?;
+    let (mark_1, _signature, commitments, nonces) =
+        append_next(&mut chain, signers, &commitments, &nonces, "shared")?;
+
+    let mut branch = chain.fork();
+    assert_eq!(branch.last_mark(), &mark_1);
+
+    let (main_2, _, _, _) =
+        append_next(&mut chain, signers, &commitments, &nonces, "main")?;
+    let (branch_2, _, _, _) =
+        append_next(&mut branch, signers, &commitments, &nonces, "branch")?;
+
+    assert_eq!(main_2.seq(), 2);
+    assert_eq!(branch_2.seq(), 2);
+    assert_ne!(main_2.hash(), branch_2.hash());
+    assert!(mark_1.precedes(&main_2));
+    assert!(mark_1.precedes(&branch_2));
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_verify_against_public_summary() -> Result<()> {
+    let signers = &["Alice", "Charlie"];
+    let (mut chain, mark_0, mut commitments, mut nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    let mut marks = vec![mark_0];
+    let mut signatures = Vec::new();
+    for info in ["one", "two", "three"] {
+        let (mark, signature, next_commitments, next_nonces) =
+            append_next(&mut chain, signers, &commitments, &nonces, info)?;
+        marks.push(mark);
+        signatures.push(signature);
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+
+    // The auditor holds only the exported public material
+    let summary = chain.group().public_summary();
+    drop(chain);
+    FrostPmChain::verify_against_public_summary(&summary, &marks, &signatures)?;
+
+    // Out-of-order signatures and missing signatures are rejected
+    signatures.swap(0, 1);
+    assert!(
+        FrostPmChain::verify_against_public_summary(
+            &summary,
+            &marks,
+            &signatures
+        )
+        .is_err()
+    );
+    assert!(
+        FrostPmChain::verify_against_public_summary(
+            &summary,
+            &marks,
+            &signatures[..2]
+        )
+        .is_err()
+    );
+
+    Ok(())
+}
+
+/// Build a `count`-mark chain and archive its genesis proof and records
+fn archived_chain(
+    count: usize,
+) -> Result<(FrostGroup, GenesisProof, Vec<MarkRecord>)> {
+    archived_chain_with(count, KdfVersion::V1)
+}
+
+fn archived_chain_with(
+    count: usize,
+    kdf_version: KdfVersion,
+) -> Result<(FrostGroup, GenesisProof, Vec<MarkRecord>)> {
+    let signers = &["Alice", "Bob"];
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Archived chain".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Medium;
+    let date_0 = Date::now();
+    let message_0 =
+        FrostPmChain::message_0(&config, res, date_0, Some("genesis"));
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (mut commitments, mut nonces) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain_with_kdf_version(
+        kdf_version,
+        res,
+        date_0,
+        Some("genesis"),
+        group.clone(),
+        signature_0,
+        &commitments,
+    )?;
+
+    let mut records = Vec::new();
+    for seq in 1..count {
+        let info = format!("record {}", seq);
+        let (mark, signature, next_commitments, next_nonces) =
+            append_next(&mut chain, signers, &commitments, &nonces, &info)?;
+        records.push(MarkRecord::new(mark, signature, commitments));
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+    let genesis =
+        GenesisProof::new(mark_0, signature_0).with_kdf_version(kdf_version);
+    Ok((group, genesis, records))
+}
+
+#[test]
+fn frost_pm_commitment_acks() -> Result<()> {
+    let (group, _, records) = archived_chain(3)?;
+    let record = &records[0];
+    let mark = record.mark();
+
+    // Each signer acknowledges its commitment with its own identity key
+    let mut identity_keys = BTreeMap::new();
+    let mut acks = Vec::new();
+    for name in ["Alice", "Bob"] {
+        let id = group.name_to_id(name)?;
+        let identity = SigningKey::new(&mut OsRng);
+        identity_keys.insert(id, VerifyingKey::from(&identity));
+        acks.push(CommitmentAck::sign(
+            &identity,
+            id,
+            mark.chain_id(),
+            mark.seq(),
+            &record.commitments()[&id],
+            &mut OsRng,
+        )?);
+    }
+    let acked = record.clone().with_acks(acks.clone());
+    assert_eq!(acked.acks().len(), 2);
+    acked.verify_acks(&identity_keys)?;
+
+    // An ack binds the participant's identity key to this mark alone
+    let alice = group.name_to_id("Alice")?;
+    let bob = group.name_to_id("Bob")?;
+    let moved = records[1].clone().with_acks(acks.clone());
+    assert!(moved.verify_acks(&identity_keys).is_err());
+    let mut swapped_keys = identity_keys.clone();
+    swapped_keys.insert(alice, identity_keys[&bob]);
+    assert!(acked.verify_acks(&swapped_keys).is_err());
+    identity_keys.remove(&bob);
+    assert!(acked.verify_acks(&identity_keys).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_replay_verify() -> Result<()> {
+    let (group, genesis, records) = archived_chain(5)?;
+    assert_eq!(records.len(), 4);
+    FrostPmChain::replay_verify(&group, &genesis, &records)?;
+
+    // A genesis proof with the wrong identity re-derives a different key_0
+    let wrong_identity = genesis.clone().with_identity(b"other identity");
+    assert!(
+        FrostPmChain::replay_verify(&group, &wrong_identity, &records)
+            .is_err()
+    );
+
+    // A genesis signature from another record fails verification
+    let wrong_signature = GenesisProof::new(
+        genesis.mark().clone(),
+        *records[0].signature(),
+    );
+    assert!(
+        FrostPmChain::replay_verify(&group, &wrong_signature, &records)
+            .is_err()
+    );
+
+    // A record archived with another record's commitments
+    let mut swapped_commitments = records.clone();
+    swapped_commitments[1] = MarkRecord::new(
+        records[1].mark().clone(),
+        *records[1].signature(),
+        records[2].commitments().clone(),
+    );
+    assert!(
+        FrostPmChain::replay_verify(&group, &genesis, &swapped_commitments)
+            .is_err()
+    );
+
+    // A record archived with another record's signature
+    let mut swapped_signature = records.clone();
+    swapped_signature[2] = MarkRecord::new(
+        records[2].mark().clone(),
+        *records[3].signature(),
+        records[2].commitments().clone(),
+    );
+    assert!(
+        FrostPmChain::replay_verify(&group, &genesis, &swapped_signature)
+            .is_err()
+    );
+
+    // A missing record breaks linkage
+    let mut missing = records.clone();
+    missing.remove(1);
+    assert!(FrostPmChain::replay_verify(&group, &genesis, &missing).is_err());
+
+    // Reordered records break linkage
+    let mut reordered = records.clone();
+    reordered.swap(2, 3);
+    assert!(
+        FrostPmChain::replay_verify(&group, &genesis, &reordered).is_err()
+    );
+
+    // The archive does not verify under a different group
+    let (other_group, _, _) = archived_chain(2)?;
+    assert!(
+        FrostPmChain::replay_verify(&other_group, &genesis, &records).is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_export_import_records() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Round-trip archive".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Medium;
+    let date_0 = Date::now();
+    let message_0 = FrostPmChain::message_0(&config, res, date_0, None::<&str>);
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (mut commitments, mut nonces) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let (mut chain, mark_0, _) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        None::<&str>,
+        group.clone(),
+        signature_0,
+        &commitments,
+    )?;
+    assert!(chain.export_records().is_empty());
+    chain.enable_history();
+
+    // A 4-mark chain
+    for i in 1..=3 {
+        let (_, _, next_commitments, next_nonces) = append_next(
+            &mut chain,
+            signers,
+            &commitments,
+            &nonces,
+            &format!("mark {}", i),
+        )?;
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+    let records = chain.export_records();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[2].mark(), chain.last_mark());
+
+    // Import into a fresh chain positioned at the tip
+    let genesis = GenesisProof::new(mark_0, signature_0);
+    let pending = PrecommitReceipt::new(4, commitments.clone());
+    let mut imported = FrostPmChain::from_records(
+        group.clone(),
+        &genesis,
+        &records,
+        &pending,
+    )?;
+    assert_eq!(imported.last_mark(), chain.last_mark());
+    assert_eq!(imported.iter_links().count(), 4);
+
+    // The 5th mark appends on the imported chain
+    let (mark_4, _, _, _) = append_next(
+        &mut imported,
+        signers,
+        &commitments,
+        &nonces,
+        "mark 4",
+    )?;
+    assert_eq!(mark_4.seq(), 4);
+    assert!(chain.last_mark().precedes(&mark_4));
+    assert_eq!(imported.export_records().len(), 4);
+
+    // A receipt for the wrong seq or other commitments is rejected
+    let stale = PrecommitReceipt::new(3, commitments.clone());
+    assert!(
+        FrostPmChain::from_records(group.clone(), &genesis, &records, &stale)
+            .is_err()
+    );
+    let (other_commitments, _) = group.round_1_commit(signers, &mut OsRng)?;
+    let other = PrecommitReceipt::new(4, other_commitments);
+    assert!(
+        FrostPmChain::from_records(group, &genesis, &records, &other).is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_chain_manifest() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, mark_0, commitments, nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    let (mark_1, _, _, _) =
+        append_next(&mut chain, signers, &commitments, &nonces, "tip")?;
+
+    let manifest = chain.export_chain_manifest();
+    assert_eq!(manifest.chain_id(), mark_0.chain_id());
+    assert_eq!(manifest.res(), ProvenanceMarkResolution::Quartile);
+    assert_eq!(manifest.group_fingerprint(), chain.group().fingerprint());
+    assert_eq!(manifest.seq(), 1);
+    assert_eq!(manifest.date(), mark_1.date());
+    verify_manifest_against_group(&manifest, chain.group())?;
+
+    // Round-trips through CBOR
+    let data = manifest.to_cbor_data();
+    assert_eq!(ChainManifest::from_cbor_data(&data)?, manifest);
+
+    // A different group does not match
+    let (other, _, _, _) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    assert!(verify_manifest_against_group(&manifest, other.group()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_manifest_verify_group() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (chain, _, _, _) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    let manifest = chain.export_chain_manifest();
+    let summary = chain.group().public_summary();
+    assert_eq!(summary.fingerprint(), chain.group().fingerprint());
+    manifest.verify_group(&summary)?;
+
+    // Right chain, wrong group
+    let (other, _, _, _) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    let error = manifest
+        .verify_group(&other.group().public_summary())
+        .unwrap_err();
+    assert!(error.to_string().contains("fingerprint"));
+
+    Ok(())
+}
+
+#[test]
+fn frost_pm_dates_are_timezone_independent() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, _mark_0, commitments, nonces) =
+        start_chain(ProvenanceMarkResolution::Medium, signers)?;
+
+    // The same instant expressed in two timezones
+    let utc = Date::from_string("2030-01-01T12:00:00Z")?;
+    let local = Date::from_string("2030-01-01T14:00:00+02:00")?;
+    assert_eq!(encode_date(&utc), encode_date(&local));
+    assert_eq!(normalize_date(&local), utc);
+    assert_eq!(
+        chain.message_next(utc, Some("tz")),
+        chain.message_next(local, Some("tz"))
+    );
+
+    let message = chain.message_next(local, Some("tz"));
+    let signature = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        &commitments,
+        &nonces,
+        message.as_bytes(),
+    )?;
+    let (next_commitments, _) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let mark_1 = chain.append_mark(
+        local,
+        Some("tz"),
+        &commitments,
+        signature,
+        &next_commitments,
+    )?;
+    assert_eq!(mark_1.date(), utc);
+
+    // Later local clock digits but an earlier instant is not monotonic
+    let earlier = Date::from_string("2030-01-01T13:00:00+02:00")?;
+    let (after_commitments, _) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    assert!(
+        chain
+            .begin_append(
+                earlier,
+                Some("tz"),
+                signers,
+                &next_commitments,
+                &after_commitments,
+            )
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn frost_pm_iter_links() -> Result<()> {
+    let signers = &["Bob", "Charlie"];
+    let (mut chain, mark_0, mut commitments, mut nonces) =
+        start_chain(ProvenanceMarkResolution::High, signers)?;
+    assert_eq!(chain.iter_links().count(), 0);
+
+    chain.enable_history();
+    let mut marks = vec![mark_0];
+    for info in ["first", "second"] {
+        let (mark, _, next_commitments, next_nonces) =
+            append_next(&mut chain, signers, &commitments, &nonces, info)?;
+        marks.push(mark);
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+
+    let links: Vec<Link> = chain.iter_links().collect();
+    assert_eq!(links.len(), 3);
+    for (link, mark) in links.iter().zip(&marks) {
+        assert_eq!(link.seq, mark.seq());
+        assert_eq!(link.key, mark.key());
+        assert_eq!(link.date, mark.date());
+    }
+    for pair in links.windows(2) {
+        assert_eq!(pair[0].next_key, pair[1].key);
+    }
+
+    // The tip's next_key is the one it committed to
+    let tip = links.last().unwrap();
+    assert!(prev_commitment_matches(chain.last_mark(), &tip.next_key)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_new_chain_returns_seq_1_receipt() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Genesis receipt".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Quartile;
+    let date_0 = Date::now();
+    let message_0 = FrostPmChain::message_0(&config, res, date_0, None::<&str>);
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let signers = &["Alice", "Charlie"];
+    let (commitments_0, nonces_0) =
+        group.round_1_commit(signers, &mut OsRng)?;
+    let signature_0 = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments_0,
+        &nonces_0,
+        message_0.as_bytes(),
+    )?;
+    let (commitments_1, _) = group.round_1_commit(signers, &mut OsRng)?;
+    let (chain, mark_0, receipt) = FrostPmChain::new_chain(
+        res,
+        date_0,
+        None::<&str>,
+        group,
+        signature_0,
+        &commitments_1,
+    )?;
+
+    assert_eq!(receipt.seq(), 1);
+    assert_eq!(receipt.root(), FrostPmChain::commitments_root(&commitments_1));
+    assert_eq!(receipt.commitments(), &commitments_1);
+    receipt.validate(chain.group())?;
+
+    // The receipt alone reproduces the key the genesis mark committed to
+    let key = FrostPmChain::kdf_next(
+        mark_0.chain_id(),
+        receipt.seq(),
+        receipt.root(),
+        mark_0.res(),
+    );
+    assert!(prev_commitment_matches(&mark_0, &key)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_precommit_receipt_validate() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (chain, mark_0, commitments_1, _) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    let group = chain.group();
+
+    // A freshly issued receipt is valid, and its root derives the key the
+    // genesis mark committed to
+    let receipt = PrecommitReceipt::new(1, commitments_1.clone());
+    receipt.validate(group)?;
+    let key = FrostPmChain::kdf_next(
+        mark_0.chain_id(),
+        1,
+        receipt.root(),
+        mark_0.res(),
+    );
+    assert!(prev_commitment_matches(&mark_0, &key)?);
+
+    // Tampered root
+    let mut root = receipt.root();
+    root[0] ^= 1;
+    let tampered = PrecommitReceipt::from_parts(
+        1,
+        receipt.ids().to_vec(),
+        commitments_1.clone(),
+        root,
+    );
+    let err = tampered.validate(group).unwrap_err();
+    assert!(err.to_string().contains("root"));
+
+    // Id list that disagrees with the commitment map
+    let charlie = group.name_to_id("Charlie")?;
+    let mismatched = PrecommitReceipt::from_parts(
+        1,
+        vec![receipt.ids()[0], charlie],
+        commitments_1.clone(),
+        receipt.root(),
+    );
+    let err = mismatched.validate(group).unwrap_err();
+    assert!(err.to_string().contains("ids"));
+
+    // Below threshold, though self-consistent
+    let mut single = commitments_1.clone();
+    single.pop_last();
+    let err = PrecommitReceipt::new(1, single).validate(group).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<FrostError>(),
+        Some(FrostError::InsufficientCommitments { required: 2, got: 1 })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_revocation_marks() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, _, commitments_1, nonces_1) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    chain.enable_history();
+    let (_, _, commitments_2, nonces_2) =
+        append_next(&mut chain, signers, &commitments_1, &nonces_1, "claim")?;
+
+    // Only existing marks can be revoked
+    let revocation = MarkInfo::Revocation {
+        target_seq: 1,
+        reason: Some("claim withdrawn".to_string()),
+    };
+    let date = Date::now();
+    let message = chain.message_next(date, Some(revocation.clone()));
+    let signature = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        &commitments_2,
+        &nonces_2,
+        message.as_bytes(),
+    )?;
+    let (commitments_3, _) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    assert!(
+        chain
+            .append_revocation(
+                date,
+                2,
+                None,
+                &commitments_2,
+                signature,
+                &commitments_3,
+            )
+            .is_err()
+    );
+
+    let mark = chain.append_revocation(
+        date,
+        1,
+        Some("claim withdrawn".to_string()),
+        &commitments_2,
+        signature,
+        &commitments_3,
+    )?;
+    assert_eq!(mark.seq(), 2);
+    assert_eq!(MarkInfo::from(mark.info().unwrap()), revocation);
+    assert_eq!(chain.revoked_sequences(), vec![1]);
+    assert_eq!(chain.iter_links().count(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_heartbeat_marks() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, _, mut commitments, mut nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    chain.enable_history();
+    assert_eq!(chain.last_heartbeat_at(), None);
+
+    let mut heartbeats = Vec::new();
+    for _ in 0..2 {
+        let MarkInfo::Heartbeat { nonce } = MarkInfo::heartbeat(&mut OsRng)
+        else {
+            unreachable!()
+        };
+        let info = MarkInfo::Heartbeat { nonce };
+        let date = Date::now();
+        let message = chain.message_next(date, Some(info.clone()));
+        let signature = chain.group().round_2_sign_as(
+            MessagePurpose::Mark,
+            signers,
+            &commitments,
+            &nonces,
+            message.as_bytes(),
+        )?;
+        let (next_commitments, next_nonces) =
+            chain.group().round_1_commit(signers, &mut OsRng)?;
+        let mark = chain.append_heartbeat(
+            date,
+            nonce,
+            &commitments,
+            signature,
+            &next_commitments,
+        )?;
+        assert_eq!(MarkInfo::from(mark.info().unwrap()), info);
+        heartbeats.push(mark);
+        (commitments, nonces) = (next_commitments, next_nonces);
+    }
+    assert_ne!(heartbeats[0].hash(), heartbeats[1].hash());
+
+    // Ordinary marks are not heartbeats
+    append_next(&mut chain, signers, &commitments, &nonces, "content")?;
+    assert_eq!(chain.heartbeat_sequences(), vec![1, 2]);
+    assert_eq!(chain.last_heartbeat_at(), Some(heartbeats[1].date()));
+
+    Ok(())
+}
+
+#[test]
+fn test_losing_precommit_race_is_distinguished() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, _, commitments_1, nonces_1) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    let (_, _, commitments_2, nonces_2) =
+        append_next(&mut chain, signers, &commitments_1, &nonces_1, "one")?;
+
+    // Two proposers hold the same precommitted seq-2 commitments, each with
+    // its own next commitments; proposer A appends first
+    let (next_b, _) = chain.group().round_1_commit(signers, &mut OsRng)?;
+    let (mark_a, signature_a, ..) =
+        append_next(&mut chain, signers, &commitments_2, &nonces_2, "A")?;
+
+    // B's append is rejected before its signature is even checked
+    let error = chain
+        .append_mark(
+            mark_a.date(),
+            Some("B"),
+            &commitments_2,
+            signature_a,
+            &next_b,
+        )
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::NextKeyAlreadyCommitted)
+    );
+
+    // Unrelated commitments are a client bug, not a lost race
+    let (stray, _) = chain.group().round_1_commit(signers, &mut OsRng)?;
+    let error = chain
+        .append_mark(mark_a.date(), Some("B"), &stray, signature_a, &next_b)
+        .unwrap_err();
+    assert_eq!(error.downcast_ref::<FrostError>(), None);
+    assert_eq!(chain.last_mark().seq(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_shared_chain_serializes_concurrent_appends() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, _, commitments_1, nonces_1) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    chain.enable_history();
+    let shared = Arc::new(SharedChain::new(chain));
+    // The Round-1 material for the next mark is only ever touched inside an
+    // append, so the chain's lock guards it too
+    let pending = Arc::new(Mutex::new((commitments_1, nonces_1)));
+
+    let threads: Vec<_> = (0..4)
+        .map(|t| {
+            let (shared, pending) = (shared.clone(), pending.clone());
+            std::thread::spawn(move || -> Result<()> {
+                for i in 0..3 {
+                    shared.append(|chain| {
+                        let mut pending = pending.lock().unwrap();
+                        let (commitments, nonces) = &*pending;
+                        let (mark, _, next_commitments, next_nonces) =
+                            append_next(
+                                chain,
+                                signers,
+                                commitments,
+                                nonces,
+                                &format!("thread {} append {}", t, i),
+                            )?;
+                        *pending = (next_commitments, next_nonces);
+                        Ok(mark)
+                    })?;
+                }
+                Ok(())
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap()?;
+    }
+
+    let chain = Arc::into_inner(shared).unwrap().into_inner()?;
+    assert_eq!(chain.last_mark().seq(), 12);
+    let links: Vec<Link> = chain.iter_links().collect();
+    assert_eq!(links.len(), 13);
+    for (seq, pair) in links.windows(2).enumerate() {
+        assert_eq!(pair[0].seq, seq as u32);
+        assert_eq!(pair[1].seq, seq as u32 + 1);
+        assert!(pair[0].date <= pair[1].date);
+        assert_eq!(pair[0].next_key, pair[1].key);
+    }
+    Ok(())
+}
+
+#[test]
+fn frost_pm_kdf_v2_binds_roster() -> Result<()> {
+    let (group, genesis, records) = archived_chain_with(3, KdfVersion::V2)?;
+    FrostPmChain::replay_verify(&group, &genesis, &records)?;
+
+    // Replaying under the wrong version re-derives different keys
+    let as_v1 = genesis.clone().with_kdf_version(KdfVersion::V1);
+    assert!(FrostPmChain::replay_verify(&group, &as_v1, &records).is_err());
+    Ok(())
+}
+
+#[test]
+fn frost_pm_on_append_hook() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, _, mut commitments, mut nonces) =
+        start_chain(ProvenanceMarkResolution::Medium, signers)?;
+    let indexed = Arc::new(Mutex::new(Vec::new()));
+    let sink = indexed.clone();
+    chain.on_append(Box::new(move |mark| {
+        sink.lock().unwrap().push(mark.clone());
+    }));
+
+    // A rejected append is never reported
+    let (next_commitments, _) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let bogus_signature =
+        chain
+            .group()
+            .round_2_sign(signers, &commitments, &nonces, b"unrelated")?;
+    assert!(
+        chain
+            .append_mark(
+                Date::now(),
+                Some("first"),
+                &commitments,
+                bogus_signature,
+                &next_commitments,
+            )
+            .is_err()
+    );
+    assert!(indexed.lock().unwrap().is_empty());
+
+    let mut marks = Vec::new();
+    for info in ["first", "second", "third"] {
+        let (mark, _, next_commitments, next_nonces) =
+            append_next(&mut chain, signers, &commitments, &nonces, info)?;
+        marks.push(mark);
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+    assert_eq!(*indexed.lock().unwrap(), marks);
+    Ok(())
+}
+
+#[test]
+fn frost_pm_stats() -> Result<()> {
+    let signers = &["Alice", "Charlie"];
+    let (mut chain, _, mut commitments, mut nonces) =
+        start_chain(ProvenanceMarkResolution::Low, signers)?;
+    assert_eq!(*chain.stats(), Stats::default());
+
+    for (i, info) in ["one", "two", "three"].into_iter().enumerate() {
+        let before = *chain.stats();
+        let (_, _, next_commitments, next_nonces) =
+            append_next(&mut chain, signers, &commitments, &nonces, info)?;
+        commitments = next_commitments;
+        nonces = next_nonces;
+        let after = *chain.stats();
+        assert_eq!(after.marks_appended, i as u64 + 1);
+        assert_eq!(after.signatures_verified, i as u64 + 1);
+        assert!(after.derivation_time > before.derivation_time);
+    }
+
+    // A signature that fails to verify counts toward nothing
+    let (next_commitments, _) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let wrong =
+        chain
+            .group()
+            .round_2_sign(signers, &commitments, &nonces, b"unrelated")?;
+    let before = *chain.stats();
+    assert!(
+        chain
+            .append_mark(
+                Date::now(),
+                Some("four"),
+                &commitments,
+                wrong,
+                &next_commitments,
+            )
+            .is_err()
+    );
+    assert_eq!(chain.stats().marks_appended, before.marks_appended);
+    assert_eq!(chain.stats().signatures_verified, before.signatures_verified);
+    Ok(())
+}
+
+#[test]
+fn frost_pm_compact_proof() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, mark_0, mut commitments, mut nonces) =
+        start_chain(ProvenanceMarkResolution::Medium, signers)?;
+    assert!(chain.compact_proof(0, 1).is_err());
+    chain.enable_history();
+
+    let mut marks = vec![mark_0];
+    for seq in 1..=5 {
+        let info = format!("mark {}", seq);
+        let (mark, _, next_commitments, next_nonces) =
+            append_next(&mut chain, signers, &commitments, &nonces, &info)?;
+        marks.push(mark);
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+
+    let proof = chain.compact_proof(0, 5)?;
+    assert_eq!(proof.len(), 5);
+    verify_compact_proof(&marks[0], marks[5].hash(), &proof)?;
+    let middle = chain.compact_proof(2, 4)?;
+    verify_compact_proof(&marks[2], marks[4].hash(), &middle)?;
+
+    // Wrong target, missing step, and a broken key chain all fail
+    assert!(verify_compact_proof(&marks[0], marks[4].hash(), &proof).is_err());
+    let mut gapped = proof.clone();
+    gapped.remove(2);
+    assert!(verify_compact_proof(&marks[0], marks[5].hash(), &gapped).is_err());
+    let mut altered = proof.clone();
+    altered[1].link.key[0] ^= 1;
+    assert!(
+        verify_compact_proof(&marks[0], marks[5].hash(), &altered).is_err()
+    );
+
+    // A forged starting mark at the last seq has no successor
+    let last = ProvenanceMark::new(
+        marks[0].res(),
+        marks[0].key().to_vec(),
+        proof[0].link.key.clone(),
+        marks[0].chain_id().to_vec(),
+        u32::MAX,
+        marks[0].date(),
+        None::<&str>,
+    )?;
+    let result = verify_compact_proof(&last, marks[5].hash(), &proof);
+    assert!(result.unwrap_err().to_string().contains("no mark can follow"));
+
+    assert!(chain.compact_proof(3, 3).is_err());
+    assert!(chain.compact_proof(0, 6).is_err());
+    Ok(())
+}
+
+#[test]
+fn frost_pm_minimum_resolution_policy() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Quartile or better".to_string(),
+    )?
+    .with_minimum_resolution(ProvenanceMarkResolution::Quartile);
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let signers = &["Alice", "Bob"];
+    let genesis = |res| {
+        let date = Date::now();
+        let message_0 =
+            FrostPmChain::message_0(group.config(), res, date, None::<String>);
+        let (commitments_0, nonces_0) =
+            group.round_1_commit(signers, &mut OsRng)?;
+        let signature_0 = group.round_2_sign_as(
+            MessagePurpose::Genesis,
+            signers,
+            &commitments_0,
+            &nonces_0,
+            message_0.as_bytes(),
+        )?;
+        let (commitments_1, _) = group.round_1_commit(signers, &mut OsRng)?;
+        FrostPmChain::new_chain(
+            res,
+            date,
+            None::<String>,
+            group.clone(),
+            signature_0,
+            &commitments_1,
+        )
+    };
+
+    let error = genesis(ProvenanceMarkResolution::Low).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::ResolutionBelowPolicy {
+            minimum: ProvenanceMarkResolution::Quartile,
+            got: ProvenanceMarkResolution::Low,
+        })
+    );
+    genesis(ProvenanceMarkResolution::Quartile)?;
+    genesis(ProvenanceMarkResolution::High)?;
+
+    Ok(())
+}
+
+/// Sign and append a mark at a caller-chosen seq
+fn append_at_seq(
+    chain: &mut FrostPmChain,
+    seq: u32,
+    signers: &[&str],
+    commitments: &Commitments,
+    nonces: &Nonces,
+) -> Result<(ProvenanceMark, Commitments, Nonces)> {
+    let date = Date::now();
+    let info = format!("seq {}", seq);
+    let message = chain.message_at_seq(seq, date, Some(info.as_str()));
+    let signature = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        commitments,
+        nonces,
+        message.as_bytes(),
+    )?;
+    let (next_commitments, next_nonces) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let mark = chain.append_mark_at_seq(
+        seq,
+        date,
+        Some(info.as_str()),
+        commitments,
+        signature,
+        &next_commitments,
+    )?;
+    Ok((mark, next_commitments, next_nonces))
+}
+
+#[test]
+fn frost_pm_append_at_seq_strict() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, mark_0, commitments, nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    assert!(chain.strict_contiguous());
+
+    // A gap is rejected before the signature is even checked
+    let (unused, unused_nonces) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let signature = chain.group().round_2_sign(
+        signers,
+        &unused,
+        &unused_nonces,
+        b"unused",
+    )?;
+    let error = chain
+        .append_mark_at_seq(
+            5,
+            Date::now(),
+            None::<String>,
+            &commitments,
+            signature,
+            &unused,
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("sequence gap"));
+
+    let (mark_1, _, _) =
+        append_at_seq(&mut chain, 1, signers, &commitments, &nonces)?;
+    assert!(mark_0.precedes(&mark_1));
+    Ok(())
+}
+
+#[test]
+fn frost_pm_append_at_seq_sparse() -> Result<()> {
+    let signers = &["Alice", "Charlie"];
+    let (mut chain, mark_0, commitments, nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    chain.set_strict_contiguous(false);
+
+    let (mark_100, commitments, nonces) =
+        append_at_seq(&mut chain, 100, signers, &commitments, &nonces)?;
+    let (mark_250, commitments, nonces) =
+        append_at_seq(&mut chain, 250, signers, &commitments, &nonces)?;
+    assert_eq!(mark_100.seq(), 100);
+    assert_eq!(mark_250.seq(), 250);
+    assert_eq!(chain.last_mark().seq(), 250);
+
+    // Each mark still reveals the key its predecessor committed to
+    assert!(prev_commitment_matches(&mark_0, mark_100.key())?);
+    assert!(prev_commitment_matches(&mark_100, mark_250.key())?);
+    // ...but provenance-mark's own sequence validation rejects the gaps
+    assert!(!mark_0.precedes(&mark_100));
+
+    // Seqs must still strictly increase
+    assert!(
+        append_at_seq(&mut chain, 250, signers, &commitments, &nonces)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn frost_pm_idempotent_append() -> Result<()> {
+    let signers = &["Bob", "Charlie"];
+    let (mut chain, _mark_0, commitments, nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    let key = [7u8; 16];
+
+    let date = Date::now();
+    let message = chain.message_next(date, Some("payment 42"));
+    let signature = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        &commitments,
+        &nonces,
+        message.as_bytes(),
+    )?;
+    let (next_commitments, next_nonces) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let mark_1 = chain.append_mark_idempotent(
+        key,
+        date,
+        Some("payment 42"),
+        &commitments,
+        signature,
+        &next_commitments,
+    )?;
+
+    // A retry with fresh commitments returns the same mark, seq unchanged
+    let (retry_commitments, _) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let retried = chain.append_mark_idempotent(
+        key,
+        date,
+        Some("payment 42"),
+        &commitments,
+        signature,
+        &retry_commitments,
+    )?;
+    assert_eq!(retried.hash(), mark_1.hash());
+    assert_eq!(chain.last_mark().seq(), 1);
+    assert_eq!(chain.stats().marks_appended, 1);
+
+    // The chain continues from the idempotent append as usual
+    let (mark_2, ..) = append_next(
+        &mut chain,
+        signers,
+        &next_commitments,
+        &next_nonces,
+        "payment 43",
+    )?;
+    assert_eq!(mark_2.seq(), 2);
+    assert!(mark_1.precedes(&mark_2));
+    Ok(())
+}
+
+#[test]
+fn frost_pm_verify_from_checkpoint() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, _mark_0, mut commitments, mut nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    for i in 1..50 {
+        let (_, _, next_commitments, next_nonces) = append_next(
+            &mut chain,
+            signers,
+            &commitments,
+            &nonces,
+            &format!("mark {}", i),
+        )?;
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+
+    // Checkpoint at seq 50
+    let date = Date::now();
+    let info = MarkInfo::Checkpoint(Box::new(chain.group().public_summary()));
+    let message = chain.message_next(date, Some(info));
+    let checkpoint_signature = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        &commitments,
+        &nonces,
+        message.as_bytes(),
+    )?;
+    let (next_commitments, next_nonces) =
+        chain.group().round_1_commit(signers, &mut OsRng)?;
+    let checkpoint = chain.append_checkpoint(
+        date,
+        &commitments,
+        checkpoint_signature,
+        &next_commitments,
+    )?;
+    assert_eq!(checkpoint.seq(), 50);
+    (commitments, nonces) = (next_commitments, next_nonces);
+
+    let mut marks = Vec::new();
+    let mut signatures = Vec::new();
+    for i in 51..=60 {
+        let (mark, signature, next_commitments, next_nonces) = append_next(
+            &mut chain,
+            signers,
+            &commitments,
+            &nonces,
+            &format!("mark {}", i),
+        )?;
+        marks.push(mark);
+        signatures.push(signature);
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+
+    // The verifier holds only the checkpoint and the marks after it
+    let summary = chain.group().public_summary();
+    drop(chain);
+    let embedded = FrostPmChain::verify_from_checkpoint(
+        &checkpoint,
+        &checkpoint_signature,
+        &marks,
+        &signatures,
+    )?;
+    assert_eq!(embedded, summary);
+
+    // A non-checkpoint mark cannot root a sub-chain
+    assert!(
+        FrostPmChain::verify_from_checkpoint(
+            &marks[0],
+            &signatures[0],
+            &marks[1..],
+            &signatures[1..],
+        )
+        .is_err()
+    );
+    // Nor can a gap in the marks after it
+    marks.remove(3);
+    signatures.remove(3);
+    assert!(
+        FrostPmChain::verify_from_checkpoint(
+            &checkpoint,
+            &checkpoint_signature,
+            &marks,
+            &signatures,
+        )
+        .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn frost_pm_diff_against() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, mark_0, mut commitments, mut nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    chain.enable_history();
+    let mut shared = vec![mark_0];
+    for info in ["one", "two"] {
+        let (mark, _, next_commitments, next_nonces) =
+            append_next(&mut chain, signers, &commitments, &nonces, info)?;
+        shared.push(mark);
+        commitments = next_commitments;
+        nonces = next_nonces;
+    }
+
+    // The replica's history splits from ours at seq 3
+    let mut replica = chain.fork();
+    append_next(&mut chain, signers, &commitments, &nonces, "three")?;
+    let (replica_3, _, replica_commitments, replica_nonces) = append_next(
+        &mut replica,
+        signers,
+        &commitments,
+        &nonces,
+        "replica three",
+    )?;
+    let (replica_4, ..) = append_next(
+        &mut replica,
+        signers,
+        &replica_commitments,
+        &replica_nonces,
+        "replica four",
+    )?;
+    let mut replica_marks = shared.clone();
+    replica_marks.extend([replica_3, replica_4]);
+
+    assert_eq!(chain.diff_against(&replica_marks)?, Some(3));
+    assert_eq!(chain.diff_against(&replica_marks[1..])?, Some(3));
+    // A prefix of our history does not diverge
+    assert_eq!(chain.diff_against(&shared)?, None);
+
+    let (unretained, ..) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    assert!(unretained.diff_against(&shared).is_err());
+    Ok(())
+}
+
+#[test]
+fn frost_pm_rejects_stale_next_commitments() -> Result<()> {
+    let signers = &["Alice", "Bob"];
+    let (mut chain, mark_0, commitments, nonces) =
+        start_chain(ProvenanceMarkResolution::Quartile, signers)?;
+    let date = Date::now();
+    let message = chain.message_next(date, Some("copy-paste"));
+    let signature = chain.group().round_2_sign_as(
+        MessagePurpose::Mark,
+        signers,
+        &commitments,
+        &nonces,
+        message.as_bytes(),
+    )?;
+
+    let error = chain
+        .append_mark(
+            date,
+            Some("copy-paste"),
+            &commitments,
+            signature,
+            &commitments,
+        )
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<FrostError>(),
+        Some(&FrostError::StaleNextCommitments)
+    );
+    assert!(
+        chain
+            .begin_append(
+                date,
+                None::<String>,
+                signers,
+                &commitments,
+                &commitments,
+            )
+            .is_err()
+    );
+    assert_eq!(chain.last_mark(), &mark_0);
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! Known-answer vectors locking the chain's key derivation as a stable
+//! protocol. A failure here means previously created chains no longer verify.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use frost_ed25519::{Identifier, round1::SigningCommitments};
+use frost_pm_test::{
+    FrostGroup, FrostGroupConfig, FrostPmChain, rand_core::OsRng,
+};
+use provenance_mark::ProvenanceMarkResolution;
+
+const ROOT: [u8; 32] = [0x42; 32];
+
+/// (resolution, seq, expected hex) with chain_id = 0x00, 0x01, ... sized to
+/// the resolution's link length
+const KDF_VECTORS: &[(ProvenanceMarkResolution, u32, &str)] = &[
+    (ProvenanceMarkResolution::Low, 0, "c4f720ee"),
+    (ProvenanceMarkResolution::Low, 1, "3b1ad5f2"),
+    (ProvenanceMarkResolution::Low, u32::MAX, "60157596"),
+    (ProvenanceMarkResolution::Medium, 0, "45c283fab38cc8d0"),
+    (ProvenanceMarkResolution::Medium, 1, "ae2ac52dd47398e0"),
+    (ProvenanceMarkResolution::Medium, u32::MAX, "e7e2208cc9a4731c"),
+    (
+        ProvenanceMarkResolution::Quartile,
+        0,
+        "c37ae0d51b90960f17c7af3cb90ebcda",
+    ),
+    (
+        ProvenanceMarkResolution::Quartile,
+        1,
+        "8022cd5b61de83ab9e9b873136aeb482",
+    ),
+    (
+        ProvenanceMarkResolution::Quartile,
+        u32::MAX,
+        "682193b01f0dd1ab93bb443296f3566d",
+    ),
+    (
+        ProvenanceMarkResolution::High,
+        0,
+        "6594ec7f48d919e7f77d42eb2bfe436147b050f8f67c10a6bb6f989c3e6b1155",
+    ),
+    (
+        ProvenanceMarkResolution::High,
+        1,
+        "d35a318ab2a637db0ec07f25c43e3f94a2ed27ca75cd55408f3b62d3285024ae",
+    ),
+    (
+        ProvenanceMarkResolution::High,
+        u32::MAX,
+        "8019b40398997cdac9cc4d09d8e9b8ad37e787a46667e0da1fe0726d56f74ebd",
+    ),
+];
+
+#[test]
+fn test_kdf_next_vectors() {
+    for &(res, seq, expected) in KDF_VECTORS {
+        let chain_id: Vec<u8> = (0..res.link_length() as u8).collect();
+        let key = FrostPmChain::kdf_next(&chain_id, seq, ROOT, res);
+        assert_eq!(key.len(), res.link_length(), "{} seq {}", res, seq);
+        assert_eq!(hex::encode(key), expected, "{} seq {}", res, seq);
+    }
+}
+
+#[test]
+fn test_commitments_root_vector() -> Result<()> {
+    let canned = [
+        (
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "00b169f0daa6873eb16a4291d05d134d9fb2280ba1e4f212cd57aa4607b1e73ef2fc7db58fb9d0c2dc65e18c0909243b1c8d75d796b88b4872190122847a7fac55359f1961",
+        ),
+        (
+            "0200000000000000000000000000000000000000000000000000000000000000",
+            "00b169f0da1c97f472654fb51ca85b8c9cc5b5bec8448e4216826d2d99187c05793cd023d63c5f49ed8cf6f1187036a77c1f1e8f9845aabf55ee6c68215241b6a068e8f373",
+        ),
+    ];
+    let mut commitments = BTreeMap::new();
+    for (id, sc) in canned {
+        commitments.insert(
+            Identifier::deserialize(&hex::decode(id)?)?,
+            SigningCommitments::deserialize(&hex::decode(sc)?)?,
+        );
+    }
+
+    assert_eq!(
+        hex::encode(FrostPmChain::commitments_root(&commitments)),
+        "42f13698801be531941322b5b3f444dd73aa2bc43386c7f958b87e0ba2d2803a"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_commitments_root_streaming_matches() -> Result<()> {
+    // A small group's commitments, reused under synthetic identifiers, give
+    // a 100-entry map without a 100-participant dealer
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Large map".to_string(),
+    )?;
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let signers = ["Alice", "Bob", "Charlie"];
+    let (small, _) = group.round_1_commit(&signers, &mut OsRng)?;
+    let small: Vec<SigningCommitments> = small.into_values().collect();
+    let mut commitments = BTreeMap::new();
+    for i in 1..=100u16 {
+        let reused = small[usize::from(i) % small.len()];
+        commitments.insert(Identifier::try_from(i)?, reused);
+    }
+    assert_eq!(commitments.len(), 100);
+
+    assert_eq!(
+        FrostPmChain::commitments_root_streaming(&commitments),
+        FrostPmChain::commitments_root(&commitments)
+    );
+    assert_eq!(
+        FrostPmChain::commitments_root_streaming(&BTreeMap::new()),
+        FrostPmChain::commitments_root(&BTreeMap::new())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_kdf_next_v2_binds_charter() -> Result<()> {
+    let names = &["Alice", "Bob", "Charlie"];
+    let a = FrostGroupConfig::new(2, names, "Charter A".to_string())?;
+    let b = FrostGroupConfig::new(2, names, "Charter B".to_string())?;
+    let (hash_a, hash_b) = (a.roster_hash()?, b.roster_hash()?);
+    assert_ne!(hash_a, hash_b);
+    assert_eq!(
+        hash_a,
+        FrostGroupConfig::new(2, names, "Charter A".to_string())?
+            .roster_hash()?
+    );
+
+    let res = ProvenanceMarkResolution::Quartile;
+    let chain_id: Vec<u8> = (0..res.link_length() as u8).collect();
+    let key_a = FrostPmChain::kdf_next_v2(&chain_id, 1, ROOT, hash_a, res);
+    let key_b = FrostPmChain::kdf_next_v2(&chain_id, 1, ROOT, hash_b, res);
+    assert_ne!(key_a, key_b);
+    assert_ne!(key_a, FrostPmChain::kdf_next(&chain_id, 1, ROOT, res));
+    assert_eq!(key_a.len(), res.link_length());
+    Ok(())
+}
@@ -0,0 +1,483 @@
+use anyhow::Result;
+use dcbor::{CBOR, Date};
+use frost_pm_test::{
+    FrostGroup, FrostGroupConfig, FrostPmChain,
+    message::{
+        MarkInfo, MessagePurpose, SignedMessage, aad_message, encode_date,
+        framed_message, length_prefix, obj_hash, parse_aad_message,
+        parse_genesis_message, parse_hash_message, parse_mark_message,
+        parse_signed_message,
+    },
+    rand_core::OsRng,
+};
+use provenance_mark::ProvenanceMarkResolution;
+
+#[test]
+fn test_obj_hash_is_deterministic() {
+    let info = "Edition #1 of collection #1".to_string();
+    let same = "Edition #1 of collection #1".to_string();
+    assert_eq!(obj_hash(Some(&info)), obj_hash(Some(&same)));
+    assert_ne!(obj_hash(Some(&info)), obj_hash(Some(&"Edition #2")));
+}
+
+#[test]
+fn test_obj_hash_of_no_info() {
+    assert_eq!(
+        hex::encode(obj_hash(None::<&String>)),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+}
+
+#[test]
+fn test_genesis_message_binds_obj_hash() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Message hashing test".to_string(),
+    )?;
+    let info = "a very large payload ".repeat(1000);
+    let message = FrostPmChain::message_0(
+        &config,
+        ProvenanceMarkResolution::Low,
+        Date::from_ymd(2025, 1, 1),
+        Some(info.clone()),
+    );
+    assert!(message.ends_with(&hex::encode(obj_hash(Some(&info)))));
+    assert!(message.len() < 512);
+    Ok(())
+}
+
+#[test]
+fn test_obj_hash_is_fixed_width_beyond_u16_info() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Message hashing test".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Low;
+    let date = Date::from_ymd(2025, 1, 1);
+    let small = FrostPmChain::message_0(&config, res, date, Some("x"));
+
+    // Info whose length no u16 prefix could carry still contributes exactly
+    // one 32-byte digest, so the signed message does not grow
+    let info = "x".repeat(u16::MAX as usize + 1);
+    let hash = obj_hash(Some(&info));
+    assert_eq!(hash.len(), 32);
+    let large = FrostPmChain::message_0(&config, res, date, Some(info));
+    assert_eq!(large.len(), small.len());
+    assert!(large.ends_with(&hex::encode(hash)));
+    Ok(())
+}
+
+#[test]
+fn test_encode_date_is_canonical_to_the_second() -> Result<()> {
+    let from_parts = Date::from_ymd_hms(2025, 6, 1, 12, 30, 15);
+    let from_timestamp = Date::from_timestamp(from_parts.timestamp() + 0.25);
+    let from_string = Date::from_string("2025-06-01T12:30:15Z")?;
+    assert_eq!(encode_date(&from_parts), encode_date(&from_timestamp));
+    assert_eq!(encode_date(&from_parts), encode_date(&from_string));
+    assert_ne!(
+        encode_date(&from_parts),
+        encode_date(&Date::from_ymd_hms(2025, 6, 1, 12, 30, 16))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_mark_info_reference_is_committed() -> Result<()> {
+    let artwork = vec![0xA5u8; 1 << 20];
+    let info = MarkInfo::reference(
+        &artwork,
+        Some("https://example.com/artwork.png".to_string()),
+    );
+    assert!(info.references(&artwork));
+    assert!(!info.references(b"a different artwork"));
+
+    // Genesis mark carrying the reference
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Off-chain references".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Medium;
+    let date = Date::now();
+    let message =
+        FrostPmChain::message_0(&config, res, date, Some(info.clone()));
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let signers = &["Alice", "Bob"];
+    let (commitments, nonces) = group.round_1_commit(signers, &mut OsRng)?;
+    let signature = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments,
+        &nonces,
+        message.as_bytes(),
+    )?;
+    let (commitments_1, _) = group.round_1_commit(signers, &mut OsRng)?;
+    let (_chain, mark, _) = FrostPmChain::new_chain(
+        res,
+        date,
+        Some(info.clone()),
+        group,
+        signature,
+        &commitments_1,
+    )?;
+
+    // The mark stays small and its info decodes back to the reference
+    assert!(mark.info().unwrap().to_cbor_data().len() < 128);
+    let committed = MarkInfo::from(mark.info().unwrap());
+    assert_eq!(committed, info);
+    assert!(committed.references(&artwork));
+    Ok(())
+}
+
+#[test]
+fn test_mark_info_from_file() -> Result<()> {
+    let path = std::env::temp_dir().join("frost-pm-mark-info-test.bin");
+    std::fs::write(&path, b"document contents")?;
+    let info = MarkInfo::from_file(&path)?;
+    std::fs::remove_file(&path)?;
+
+    assert!(info.references(b"document contents"));
+    let MarkInfo::Reference { uri: Some(uri), .. } = &info else {
+        panic!("expected a reference with a URI");
+    };
+    assert!(uri.starts_with("file://"));
+    assert!(uri.ends_with("frost-pm-mark-info-test.bin"));
+
+    // Inline info is never mistaken for a reference
+    let inline = MarkInfo::from(CBOR::from("plain text"));
+    assert_eq!(inline, MarkInfo::Inline(CBOR::from("plain text")));
+    Ok(())
+}
+
+#[test]
+fn test_mark_info_revocation_round_trip() {
+    for reason in [None, Some("superseded".to_string())] {
+        let info = MarkInfo::Revocation { target_seq: 7, reason };
+        assert_eq!(MarkInfo::from(CBOR::from(info.clone())), info);
+        assert!(!info.references(b""));
+    }
+}
+
+#[test]
+fn test_mark_info_heartbeat_round_trip() {
+    let info = MarkInfo::heartbeat(&mut OsRng);
+    assert_eq!(MarkInfo::from(CBOR::from(info.clone())), info);
+    assert_ne!(MarkInfo::heartbeat(&mut OsRng), info);
+    assert!(!info.references(b""));
+
+    // A nonce of the wrong length is inline info
+    let mut map = dcbor::Map::new();
+    map.insert("heartbeat", CBOR::to_byte_string([0u8; 8]));
+    let cbor = CBOR::from(map);
+    assert_eq!(MarkInfo::from(cbor.clone()), MarkInfo::Inline(cbor));
+}
+
+#[test]
+fn test_mark_info_checkpoint_round_trip() -> Result<()> {
+    let config = FrostGroupConfig::with_identifiers(
+        2,
+        &[("Alice", 3), ("Bob", 7), ("Charlie", 9)],
+        "Checkpointed".to_string(),
+    )?;
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let info = MarkInfo::Checkpoint(Box::new(group.public_summary()));
+    assert_eq!(MarkInfo::from(CBOR::from(info.clone())), info);
+
+    // A checkpoint map with an extra key is inline info
+    let mut cbor = CBOR::from(info).try_into_map()?;
+    cbor.insert("extra", 1);
+    let cbor = CBOR::from(cbor);
+    assert_eq!(MarkInfo::from(cbor.clone()), MarkInfo::Inline(cbor));
+    Ok(())
+}
+
+#[test]
+fn test_signatures_do_not_cross_purposes() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Purpose separation".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Medium;
+    let date = Date::now();
+    let message_0 = FrostPmChain::message_0(&config, res, date, None::<String>);
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let signers = &["Alice", "Bob"];
+    let sign = |purpose| -> Result<_> {
+        let (commitments, nonces) = group.round_1_commit(signers, &mut OsRng)?;
+        group.round_2_sign_as(
+            purpose,
+            signers,
+            &commitments,
+            &nonces,
+            message_0.as_bytes(),
+        )
+    };
+
+    // The same bytes signed for each purpose verify only as that purpose
+    let purposes = [
+        MessagePurpose::Genesis,
+        MessagePurpose::Mark,
+        MessagePurpose::Application,
+    ];
+    for signed_as in purposes {
+        let signature = sign(signed_as)?;
+        for verified_as in purposes {
+            let result =
+                group.verify_as(verified_as, message_0.as_bytes(), &signature);
+            assert_eq!(result.is_ok(), signed_as == verified_as);
+        }
+    }
+
+    // An application signature over genesis bytes cannot start a chain
+    let (commitments_1, _) = group.round_1_commit(signers, &mut OsRng)?;
+    let application_signature = sign(MessagePurpose::Application)?;
+    assert!(
+        FrostPmChain::new_chain(
+            res,
+            date,
+            None::<String>,
+            group.clone(),
+            application_signature,
+            &commitments_1,
+        )
+        .is_err()
+    );
+    let genesis_signature = sign(MessagePurpose::Genesis)?;
+    FrostPmChain::new_chain(
+        res,
+        date,
+        None::<String>,
+        group.clone(),
+        genesis_signature,
+        &commitments_1,
+    )?;
+    Ok(())
+}
+
+#[test]
+fn test_new_chain_verifies_exactly_message_0() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Single genesis builder".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Quartile;
+    // Sub-second precision that the signed encoding drops
+    let date = Date::from_timestamp(1_750_000_000.75);
+    let info = Some("genesis info");
+    let message_0 = FrostPmChain::message_0(&config, res, date, info);
+    assert_eq!(
+        message_0,
+        FrostPmChain::message_0_with_predecessor(&config, res, date, info, None)
+    );
+
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let signers = &["Bob", "Charlie"];
+    let sign = |message: &[u8]| -> Result<_> {
+        let (commitments, nonces) = group.round_1_commit(signers, &mut OsRng)?;
+        group.round_2_sign_as(
+            MessagePurpose::Genesis,
+            signers,
+            &commitments,
+            &nonces,
+            message,
+        )
+    };
+    let (commitments_1, _) = group.round_1_commit(signers, &mut OsRng)?;
+    let new_chain = |signature| {
+        FrostPmChain::new_chain(
+            res,
+            date,
+            info,
+            group.clone(),
+            signature,
+            &commitments_1,
+        )
+    };
+
+    // Any other bytes, even a trailing newline, are rejected
+    let mut extended = message_0.clone().into_bytes();
+    extended.push(b'\n');
+    assert!(new_chain(sign(&extended)?).is_err());
+    new_chain(sign(message_0.as_bytes())?)?;
+    Ok(())
+}
+
+#[test]
+fn test_length_prefix_rejects_overflow() -> Result<()> {
+    assert_eq!(length_prefix(5)?, [0, 0, 0, 5]);
+    assert_eq!(length_prefix(u32::MAX as usize)?, [0xff; 4]);
+    // A payload one byte past u32::MAX must not wrap to a zero prefix
+    assert!(length_prefix(u32::MAX as usize + 1).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_chain_messages() -> Result<()> {
+    // A charter whose content mimics the structural fields around it
+    let charter = "Line one\nDate: 00\nInfo Hash: 00, Bob".to_string();
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        charter.clone(),
+    )?;
+    let res = ProvenanceMarkResolution::Medium;
+    let date = Date::from_ymd_hms(2025, 6, 1, 12, 30, 15);
+    let info = Some("parsed info");
+
+    let message_0 = FrostPmChain::message_0(&config, res, date, info);
+    let genesis = parse_genesis_message(&message_0)?;
+    assert_eq!(genesis.res, res);
+    assert_eq!((genesis.min_signers, genesis.max_signers), (2, 3));
+    assert_eq!(genesis.participants, ["Alice", "Bob", "Charlie"]);
+    assert_eq!(genesis.charter, charter);
+    assert_eq!(genesis.seq, None);
+    assert_eq!(genesis.date, date);
+    assert_eq!(genesis.info_hash, obj_hash(info.as_ref()));
+    assert_eq!(genesis.predecessor_chain, None);
+
+    let tip_hash = [7u8; 16];
+    let continued = FrostPmChain::message_0_with_predecessor_chain(
+        &config,
+        res,
+        date,
+        info,
+        &tip_hash,
+    );
+    let parsed = parse_genesis_message(&continued)?;
+    assert_eq!(parsed.predecessor_chain.as_deref(), Some(&tip_hash[..]));
+    assert_eq!(parsed.predecessor_key, None);
+
+    // A mark message from a live chain, through the purpose dispatcher
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let signers = &["Alice", "Bob"];
+    let (commitments, nonces) = group.round_1_commit(signers, &mut OsRng)?;
+    let signature = group.round_2_sign_as(
+        MessagePurpose::Genesis,
+        signers,
+        &commitments,
+        &nonces,
+        message_0.as_bytes(),
+    )?;
+    let (commitments_1, _) = group.round_1_commit(signers, &mut OsRng)?;
+    let (chain, ..) = FrostPmChain::new_chain(
+        res,
+        date,
+        info,
+        group,
+        signature,
+        &commitments_1,
+    )?;
+    let message_1 = chain.message_next(date, None::<String>);
+    let framed = framed_message(MessagePurpose::Mark, message_1.as_bytes());
+    let SignedMessage::Mark(mark) = parse_signed_message(&framed)? else {
+        panic!("expected a mark message");
+    };
+    assert_eq!(mark, parse_mark_message(&message_1)?);
+    assert_eq!(mark.seq, Some(1));
+    assert_eq!(mark.charter, charter);
+    assert_eq!(mark.info_hash, obj_hash(None::<&String>));
+
+    // Each kind of message is only accepted by its own parser
+    assert!(parse_mark_message(&message_0).is_err());
+    assert!(parse_genesis_message(&message_1).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_application_messages() -> Result<()> {
+    let digest = [0xabu8; 32];
+    assert_eq!(parse_hash_message(&digest)?, digest);
+    let aad = aad_message(b"request-42", b"payload")?;
+    assert_eq!(
+        parse_aad_message(&aad)?,
+        (b"request-42".as_slice(), b"payload".as_slice())
+    );
+
+    let frame = |purpose, message: &[u8]| framed_message(purpose, message);
+    let app = MessagePurpose::Application;
+    assert_eq!(
+        parse_signed_message(&frame(MessagePurpose::Prehashed, &digest))?,
+        SignedMessage::Prehashed(digest)
+    );
+    assert_eq!(
+        parse_signed_message(&frame(MessagePurpose::Aad, &aad))?,
+        SignedMessage::WithAad {
+            aad: b"request-42".to_vec(),
+            message: b"payload".to_vec(),
+        }
+    );
+    assert_eq!(
+        parse_signed_message(&frame(app, b"plain"))?,
+        SignedMessage::Application(b"plain".to_vec())
+    );
+
+    // Application messages that look like other purposes stay application
+    // messages; only the tag decides
+    let lookalikes = [
+        [b"PM:v1/prehashed".as_slice(), &digest].concat(),
+        [b"PM:v1/aad".as_slice(), &aad].concat(),
+    ];
+    for lookalike in lookalikes {
+        assert_eq!(
+            parse_signed_message(&frame(app, &lookalike))?,
+            SignedMessage::Application(lookalike)
+        );
+    }
+    assert_eq!(
+        parse_signed_message(&frame(MessagePurpose::Attestation, b"summary"))?,
+        SignedMessage::Attestation(b"summary".to_vec())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parsers_reject_malformed_messages() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Malformed input".to_string(),
+    )?;
+    let res = ProvenanceMarkResolution::Quartile;
+    let date = Date::from_ymd(2025, 1, 1);
+    let message = FrostPmChain::message_0(&config, res, date, None::<String>);
+    parse_genesis_message(&message)?;
+
+    let malformed = [
+        // Truncated or extended
+        message[..message.len() - 1].to_string(),
+        message[..message.find("\nDate").unwrap()].to_string(),
+        format!("{}\n", message),
+        // Charter length prefix disagreeing with the charter
+        message.replace("Charter: 15:", "Charter: 14:"),
+        message.replace("Charter: 15:", "Charter: 16:"),
+        message.replace("Charter: 15:", "Charter: 015:"),
+        message.replace("Charter: 15:", "Charter: 99999:"),
+        // Structural fields out of shape
+        message.replace("2 of 3", "+2 of 3"),
+        message.replace("quartile", "ultra"),
+        message.replace("Alice, ", ""),
+        message.replace("Info Hash: e3", "Info Hash: "),
+        message.replace("Date: ", "Date: 00"),
+        message.replace("FROST", "Frost"),
+    ];
+    for bad in &malformed {
+        assert!(parse_genesis_message(bad).is_err(), "accepted: {:?}", bad);
+    }
+
+    // Binary messages with bad tags or lengths
+    assert!(parse_hash_message(&[0; 31]).is_err());
+    assert!(parse_hash_message(&[0; 33]).is_err());
+    let mut aad = aad_message(b"abc", b"")?;
+    aad.truncate(aad.len() - 1);
+    assert!(parse_aad_message(&aad).is_err());
+    assert!(parse_aad_message(b"\x00\x00").is_err());
+    assert!(parse_signed_message(&[]).is_err());
+    assert!(parse_signed_message(&[0x09, b'x']).is_err());
+    let not_utf8 = [MessagePurpose::Genesis.tag(), 0xff];
+    assert!(parse_signed_message(&not_utf8).is_err());
+    Ok(())
+}
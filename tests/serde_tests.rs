@@ -0,0 +1,76 @@
+#![cfg(feature = "serde")]
+
+use std::time::Duration;
+
+use anyhow::Result;
+use frost_pm_test::{
+    FrostGroup, FrostGroupConfig, PrecommitReceipt, pm_chain::AppendTimings,
+    rand_core::OsRng,
+};
+
+#[test]
+fn test_config_serde_round_trip() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Zoe", "Anna", "Mike"],
+        "Serde round trip".to_string(),
+    )?;
+    let json = serde_json::to_string(&config)?;
+    let restored: FrostGroupConfig = serde_json::from_str(&json)?;
+
+    assert_eq!(restored.min_signers(), 2);
+    assert_eq!(restored.charter(), "Serde round trip");
+    assert_eq!(restored.participant_ids(), config.participant_ids());
+    assert_eq!(
+        restored.participant_names_in_id_order(),
+        ["Zoe", "Anna", "Mike"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_config_deserialize_validates() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob"],
+        "Validation".to_string(),
+    )?;
+    let mut value = serde_json::to_value(&config)?;
+    value["min_signers"] = 3.into();
+    assert!(serde_json::from_value::<FrostGroupConfig>(value).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_append_timings_serde_round_trip() -> Result<()> {
+    let timings = AppendTimings {
+        root: Duration::from_micros(150),
+        kdf: Duration::from_micros(80),
+        verify: Duration::from_millis(35),
+        precommit: Duration::from_micros(170),
+        finalize: Duration::from_micros(75),
+        total: Duration::from_micros(35_475),
+    };
+    let json = serde_json::to_string(&timings)?;
+    assert_eq!(serde_json::from_str::<AppendTimings>(&json)?, timings);
+    Ok(())
+}
+
+#[test]
+fn test_precommit_receipt_serde_round_trip() -> Result<()> {
+    let config = FrostGroupConfig::new(
+        2,
+        &["Alice", "Bob", "Charlie"],
+        "Receipt round trip".to_string(),
+    )?;
+    let group = FrostGroup::new_with_trusted_dealer(config, &mut OsRng)?;
+    let (commitments, _) =
+        group.round_1_commit(&["Alice", "Charlie"], &mut OsRng)?;
+    let receipt = PrecommitReceipt::new(7, commitments);
+
+    let json = serde_json::to_string(&receipt)?;
+    let restored: PrecommitReceipt = serde_json::from_str(&json)?;
+    assert_eq!(restored, receipt);
+    restored.validate(&group)?;
+    Ok(())
+}